@@ -0,0 +1,142 @@
+//! Derive macros for the [gpu](https://docs.rs/gpu) crate.
+
+#![warn(missing_docs)]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use std::collections::HashMap;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+/// Derives `gpu::Vertex` for a `#[repr(C)]` struct.
+///
+/// Every field becomes a `VertexAttribute` with the field's offset and the struct's size as
+/// stride. The attribute location defaults to the field's position, the components are
+/// converted to float and advance once per vertex. These can be changed with the `vertex` field
+/// attribute:
+/// ```rust,ignore
+/// #[derive(Vertex)]
+/// #[repr(C)]
+/// struct MyVertex {
+///     position : [f32; 3],
+///     #[vertex(location = 3, normalized)]
+///     color    : [u8; 4],
+///     #[vertex(integer, divisor = 1)]
+///     id       : u32
+/// }
+/// ```
+/// Two fields with the same location, explicit or defaulted, are rejected at compile time.
+#[proc_macro_derive(Vertex, attributes(vertex))]
+pub fn derive_vertex(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match vertex(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into()
+    }
+}
+
+fn is_repr_c(input: &DeriveInput) -> bool {
+    input.attrs.iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .any(|meta| match meta {
+            Meta::List(list) => list.nested.iter().any(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.is_ident("C"),
+                _ => false
+            }),
+            _ => false
+        })
+}
+
+/// Location, kind and divisor parsed from a field's `#[vertex(...)]` attributes.
+struct FieldOptions {
+    location : Option<usize>,
+    kind     : Option<TokenStream2>,
+    divisor  : Option<usize>
+}
+
+fn field_options(field: &syn::Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions { location: None, kind: None, divisor: None };
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("vertex")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(syn::Error::new_spanned(meta, "expected #[vertex(...)]"))
+        };
+        for nested in list.nested.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("location") => {
+                    match &name_value.lit {
+                        Lit::Int(int) => options.location = Some(int.base10_parse()?),
+                        lit => return Err(syn::Error::new_spanned(lit, "expected an integer location"))
+                    }
+                },
+                NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("divisor") => {
+                    match &name_value.lit {
+                        Lit::Int(int) => options.divisor = Some(int.base10_parse()?),
+                        lit => return Err(syn::Error::new_spanned(lit, "expected an integer divisor"))
+                    }
+                },
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("normalized") => {
+                    options.kind = Some(quote!(::gpu::AttributeKind::Normalized));
+                },
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("integer") => {
+                    options.kind = Some(quote!(::gpu::AttributeKind::Integer));
+                },
+                nested => return Err(syn::Error::new_spanned(nested, "expected `location = N`, `divisor = N`, `normalized` or `integer`"))
+            }
+        }
+    }
+    Ok(options)
+}
+
+fn vertex(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(input, "Vertex can only be derived for structs with named fields"))
+        },
+        _ => return Err(syn::Error::new_spanned(input, "Vertex can only be derived for structs"))
+    };
+    if !is_repr_c(input) {
+        return Err(syn::Error::new_spanned(&input.ident, "Vertex requires #[repr(C)]"))
+    }
+
+    let mut attributes = Vec::new();
+    let mut locations  = HashMap::new();
+    for (index, field) in fields.iter().enumerate() {
+        let options  = field_options(field)?;
+        let location = options.location.unwrap_or(index);
+        if let Some(other) = locations.insert(location, field.ident.clone()) {
+            let message = format!("location {} is already used by `{}`", location, quote!(#other));
+            return Err(syn::Error::new_spanned(field, message))
+        }
+        let name     = &field.ident;
+        let ty       = &field.ty;
+        let kind     = options.kind.map(|kind| quote!(.with_kind(#kind)));
+        let divisor  = options.divisor.map(|divisor| quote!(.with_divisor(#divisor)));
+        attributes.push(quote! {
+            (#location, <#ty as ::gpu::VertexComponent>::attribute()
+                #kind
+                #divisor
+                .with_stride(stride)
+                .with_offset(unsafe {
+                    (::std::ptr::addr_of!((*base).#name) as *const u8).offset_from(base as *const u8) as usize
+                }))
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::gpu::Vertex for #name #ty_generics #where_clause {
+            fn attributes() -> ::std::vec::Vec<(usize, ::gpu::VertexAttribute)> {
+                let stride = ::std::mem::size_of::<Self>();
+                let vertex = ::std::mem::MaybeUninit::<Self>::uninit();
+                let base   = vertex.as_ptr();
+                vec![#(#attributes),*]
+            }
+        }
+    })
+}
@@ -1,9 +1,37 @@
 mod program;
+mod program_builder;
+mod program_pipeline;
+mod program_cache;
+mod reloadable_program;
+mod program_reflection;
+mod uniform;
+mod uniform_type;
+mod uniform_value;
+mod storage_image;
+mod raster;
 mod raster_program;
+mod transform_feedback;
+mod raster_geometry;
+mod raster_state;
+mod draw_commands;
 mod compute_program;
 mod clear_program;
 
 pub use program::*;
+pub use program_builder::*;
+pub use program_pipeline::*;
+pub use program_cache::*;
+pub use reloadable_program::*;
+pub use program_reflection::*;
+pub use uniform::*;
+pub use uniform_type::*;
+pub use uniform_value::*;
+pub use storage_image::*;
+pub use raster::*;
 pub use raster_program::*;
+pub use transform_feedback::*;
+pub use raster_geometry::*;
+pub use raster_state::*;
+pub use draw_commands::*;
 pub use compute_program::*;
 pub use clear_program::*;
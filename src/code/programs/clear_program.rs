@@ -1,4 +1,4 @@
-use crate::{Context, Framebuffer, GLContext};
+use crate::{Context, Framebuffer, GLContext, Scissor};
 
 
 
@@ -11,7 +11,8 @@ pub struct ClearProgram {
     _gl: GLContext,
     color: (f32, f32, f32, f32),
     depth: f32,
-    stencil: i32
+    stencil: i32,
+    scissor: Option<Scissor>
 }
 
 impl ClearProgram {
@@ -28,7 +29,8 @@ impl ClearProgram {
         let color = (0.0, 0.0, 0.0, 0.0);
         let depth = 1.0; // FIXME: is it default?
         let stencil = 0; // FIXME: is it default?
-        Self { _gl: gl, color, depth, stencil}
+        let scissor = None;
+        Self { _gl: gl, color, depth, stencil, scissor }
     }
 
     /// Sets the color clear value.
@@ -46,14 +48,24 @@ impl ClearProgram {
     /// Gets the stencil clear value.
     pub fn stencil(&self) -> i32 { self.stencil }
 
-    /// Clear the target `Framebuffer` using the buffer bit mask.
+    /// Sets the `Scissor` rectangle limiting the cleared area. `None` clears the whole `Framebuffer`.
+    pub fn set_scissor(&mut self, scissor: Option<Scissor>) { self.scissor = scissor; }
+    /// Gets the `Scissor` rectangle.
+    pub fn scissor(&self) -> Option<Scissor> { self.scissor }
+
+    /// Clear the target `Framebuffer` using the buffer bit mask. Every channel, depth and stencil bit
+    /// selected by the mask is cleared, regardless of the write masks of previous raster calls.
     /// Here is an example that clears color, depth and stencil in a single call:
     /// ```rust,ignore
     /// clear(framebuffer, ClearProgram::COLOR | ClearProgram::DEPTH | ClearProgram::STENCIL)
     /// ```
     pub fn clear(&self, framebuffer:&Framebuffer, clear_mask: u32) {
+        framebuffer.bind();
+        Scissor::bind(self.scissor.as_ref());
         unsafe {
-            framebuffer.bind();
+            gl::ColorMask(gl::TRUE, gl::TRUE, gl::TRUE, gl::TRUE);
+            gl::DepthMask(gl::TRUE);
+            gl::StencilMask(!0);
             gl::ClearColor(self.color.0, self.color.1, self.color.2, self.color.3);
             gl::ClearDepthf(self.depth);
             gl::ClearStencil(self.stencil);
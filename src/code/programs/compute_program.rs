@@ -2,7 +2,32 @@ use crate::prelude::*;
 use crate::Context;
 
 use crate::Program;
+use crate::ProgramBuilder;
+use crate::ProgramCache;
+use crate::PreprocessedSource;
+use crate::ShaderStage;
 use crate::ComputeShader;
+use crate::Buffer;
+
+/// A dispatch command read from a `Buffer` by `ComputeProgram::compute_indirect`.
+#[repr(C)]
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub struct DispatchIndirectCommand {
+    /// Number of work groups in x.
+    pub x : u32,
+    /// Number of work groups in y.
+    pub y : u32,
+    /// Number of work groups in z.
+    pub z : u32
+}
+
+impl DispatchIndirectCommand {
+    /// Creates a new `DispatchIndirectCommand` with `(x, y, z)` work groups.
+    pub fn new(groups: (u32, u32, u32)) -> Self {
+        let (x, y, z) = groups;
+        Self { x, y, z }
+    }
+}
 
 /// A program used for computing.
 #[derive(Shrinkwrap)]
@@ -11,32 +36,37 @@ pub struct ComputeProgram {
     /// Program base object.
     #[shrinkwrap(main_field)]
     pub program : Program,
+    local_size  : (usize, usize, usize)
 }
 
 impl ComputeProgram {
     /// Creates a new `RasterProgram` with a `FragmentShader` and ` VertexShader`.
     pub fn new(context: &Context, compute_shader:&ComputeShader) -> Result<Self, String> {
-        let program = Program::new(context);
-        unsafe {
-            gl::AttachShader(program.resource(), compute_shader.resource());
-            gl::LinkProgram(program.resource());
+        ProgramBuilder::new()
+            .with_shader(compute_shader)
+            .build_compute(context)
+    }
 
-            // Check for linking errors
-            let mut is_linked = gl::FALSE as i32;
-            gl::GetProgramiv(program.resource(), gl::LINK_STATUS, &mut is_linked);
-            if is_linked == gl::FALSE as i32 {
-                let buffer_size = 4096;
-                let mut length = 0;
-                let mut buffer : [u8; 4096] = [0; 4096];
-                gl::GetProgramInfoLog(program.resource(), buffer_size, &mut length, buffer.as_mut_ptr() as *mut i8);
-                let err = String::from_raw_parts(buffer.as_mut_ptr(), length as usize, buffer_size as usize);
-                return Err(err)
-            }
-        }
+    /// Creates a new `ComputeProgram` from a preprocessed compute shader source, loading it from
+    /// the `ProgramCache` when possible.
+    pub fn new_cached(context: &Context, cache: &ProgramCache, source: &PreprocessedSource) -> Result<Self, String> {
+        let program = cache.program(context, &[(ShaderStage::Compute, source)])?;
+        Ok(Self::from_program(program))
+    }
 
-        Ok(Self {program})
+    /// Wraps a linked `Program` with a compute stage.
+    pub(crate) fn from_program(program: Program) -> Self {
+        let local_size = unsafe {
+            let mut local_size = [0; 3];
+            gl::GetProgramiv(program.resource(), gl::COMPUTE_WORK_GROUP_SIZE, local_size.as_mut_ptr());
+            (local_size[0] as usize, local_size[1] as usize, local_size[2] as usize)
+        };
+        Self {program, local_size}
     }
 
+    /// Gets the work group size declared in the shader with `layout(local_size_x = ...) in`.
+    pub fn local_size(&self) -> (usize, usize, usize) { self.local_size }
+
     pub(crate) fn use_(&self) {
         unsafe {
             gl::UseProgram(self.resource());
@@ -44,12 +74,50 @@ impl ComputeProgram {
     }
 
     /// Launch one or more compute work `groups`.
+    ///
+    /// The shader writes aren't visible to the following commands until a `MemoryBarrier` is
+    /// issued with `Context::memory_barrier`.
     pub fn compute(&self, groups: (usize, usize, usize)) {
         unsafe {
             self.use_();
             gl::DispatchCompute(groups.0 as u32, groups.1 as u32, groups.2 as u32);
         }
     }
+
+    /// Launch enough work groups to cover the `(x, y, z)` `invocations`. The number of groups in
+    /// each dimension is rounded up, so the shader must discard the invocations out of bounds.
+    /// It fails if the number of groups exceeds the device's maximum work group count.
+    pub fn compute_invocations(&self, invocations: (usize, usize, usize)) -> Result<(), String> {
+        let (local_x, local_y, local_z) = self.local_size();
+        let groups = [
+            invocations.0.div_ceil(local_x),
+            invocations.1.div_ceil(local_y),
+            invocations.2.div_ceil(local_z)
+        ];
+        for (index, groups) in groups.iter().enumerate() {
+            let max_groups = unsafe {
+                let mut max_groups = 0;
+                gl::GetIntegeri_v(gl::MAX_COMPUTE_WORK_GROUP_COUNT, index as u32, &mut max_groups);
+                max_groups as usize
+            };
+            if *groups > max_groups {
+                return Err(format!("{} work groups in dimension {} exceed the maximum of {}", groups, index, max_groups))
+            }
+        }
+        self.compute((groups[0], groups[1], groups[2]));
+        Ok(())
+    }
+
+    /// Launch the compute work groups read from a `DispatchIndirectCommand` at `offset` bytes of a
+    /// `Buffer`. If the command was written by a shader, a `MemoryBarrier::COMMAND` must be issued
+    /// first.
+    pub fn compute_indirect(&self, buffer: &Buffer, offset: usize) {
+        self.use_();
+        buffer.bind_to(gl::DISPATCH_INDIRECT_BUFFER);
+        unsafe {
+            gl::DispatchComputeIndirect(offset as isize);
+        }
+    }
 }
 
 //FIXME: Fix these tests.
@@ -0,0 +1,93 @@
+use crate::Buffer;
+
+/// A non-indexed draw command read from a `Buffer` by indirect raster calls.
+#[repr(C)]
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub struct DrawArraysIndirectCommand {
+    /// Number of vertices.
+    pub count          : u32,
+    /// Number of instances.
+    pub instance_count : u32,
+    /// First vertex.
+    pub first          : u32,
+    /// First instance for instanced attributes.
+    pub base_instance  : u32
+}
+
+impl DrawArraysIndirectCommand {
+    /// Creates a new `DrawArraysIndirectCommand` drawing a single instance of `count` vertices
+    /// starting at `first`.
+    pub fn new(first: u32, count: u32) -> Self {
+        let instance_count = 1;
+        let base_instance  = 0;
+        Self { count, instance_count, first, base_instance }
+    }
+}
+
+/// An indexed draw command read from a `Buffer` by indirect raster calls.
+#[repr(C)]
+#[derive(Clone,Copy,Debug,Default,PartialEq)]
+pub struct DrawElementsIndirectCommand {
+    /// Number of indices.
+    pub count          : u32,
+    /// Number of instances.
+    pub instance_count : u32,
+    /// First index.
+    pub first_index    : u32,
+    /// A value added to every index before fetching the vertex.
+    pub base_vertex    : i32,
+    /// First instance for instanced attributes.
+    pub base_instance  : u32
+}
+
+impl DrawElementsIndirectCommand {
+    /// Creates a new `DrawElementsIndirectCommand` drawing a single instance of `count` indices
+    /// starting at `first_index`.
+    pub fn new(first_index: u32, count: u32) -> Self {
+        let instance_count = 1;
+        let base_vertex    = 0;
+        let base_instance  = 0;
+        Self { count, instance_count, first_index, base_vertex, base_instance }
+    }
+}
+
+/// The instances drawn by instanced raster calls.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct InstanceRange {
+    /// The first instance for instanced attributes.
+    pub first : usize,
+    /// The number of instances.
+    pub count : usize
+}
+
+impl InstanceRange {
+    /// Creates a new `InstanceRange` with `count` instances starting at `first`.
+    pub fn new(first: usize, count: usize) -> Self {
+        Self { first, count }
+    }
+}
+
+/// Tightly packed draw commands read from a `Buffer` by multi indirect raster calls.
+#[derive(Clone,Copy)]
+pub struct IndirectCommands<'a> {
+    /// The `Buffer` holding the commands.
+    pub buffer     : &'a Buffer,
+    /// Offset in bytes of the first command.
+    pub offset     : usize,
+    /// Number of commands.
+    pub draw_count : usize
+}
+
+impl<'a> IndirectCommands<'a> {
+    /// Creates a new `IndirectCommands` reading a single command at `offset` bytes of a `Buffer`.
+    pub fn new(buffer: &'a Buffer, offset: usize) -> Self {
+        let draw_count = 1;
+        Self { buffer, offset, draw_count }
+    }
+
+    /// Sets the number of commands.
+    pub fn with_draw_count(mut self, draw_count: usize) -> Self {
+        self.draw_count = draw_count;
+        self
+    }
+}
@@ -1,12 +1,28 @@
-use crate::{Context, GLContext, Sampler, Image2D};
+use crate::{Context, ContextInfo, GlslDialect, GLContext, Sampler, Image, Image2D, StorageImageBinding, Buffer, BufferRange, ProgramReflection, UniformType, Uniform};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::CString;
+#[cfg(feature = "wgsl")]
+use crate::{BindingKind, WgslBindings};
 
 type ProgramResource = u32;
 
+/// The location of an active uniform and what can be set to it.
+#[derive(Clone,Copy)]
+struct UniformLocation {
+    location     : usize,
+    uniform_type : UniformType,
+    /// The number of array elements from the location on, or `None` if it isn't an array.
+    array_len    : Option<usize>
+}
+
 /// A structure representing a GPU program.
 #[derive(Clone)]
 pub struct Program {
     pub(crate) gl : GLContext,
-    resource      : ProgramResource
+    resource      : ProgramResource,
+    locations     : RefCell<HashMap<String, Option<UniformLocation>>>,
+    lenient       : bool
 }
 
 impl Program {
@@ -16,146 +32,264 @@ impl Program {
         let resource = unsafe {
             gl::CreateProgram()
         };
-        Self { gl, resource}
+        let locations = Default::default();
+        let lenient = false;
+        Self { gl, resource, locations, lenient }
     }
 
     /// Gets the `ProgramResource` object.
     pub fn resource(&self) -> ProgramResource { self.resource }
 
-// FIXME: These parts were removed because glow uses a minimum set of GL x GLES x WEBGL.
-// These functions can be included in a trait which can be implemented for backends that supports it.
-//    fn bind_buffer(&mut self, buffer: &Buffer, index: u32) {
-//        unsafe {
-//            self::BindBufferBase(gl::SHADER_STORAGE_BUFFER, index, buffer.get_id());
-//        }
-//    }
-
-    //FIXME: Create Sampler1D, Sampler2D and Sampler2D? What would be the benefits of strong types here?
-    /// Binds a `Sampler` at `index`.
-    pub fn bind_sampler(&self, sampler:&Sampler, index: usize) {
+    /// Links the attached shaders, returning the info log on failure.
+    pub(crate) fn link(&self) -> Result<(), String> {
         unsafe {
-            gl::ActiveTexture(gl::TEXTURE0 + index as u32);
-            gl::BindTexture(sampler.image.type_(), sampler.image.internal());
-            gl::BindSampler(index as u32, sampler.internal());
-            gl::UseProgram(self.resource());
-            gl::Uniform1i(index as i32, index as i32);
+            gl::LinkProgram(self.resource());
+            let mut is_linked = gl::FALSE as i32;
+            gl::GetProgramiv(self.resource(), gl::LINK_STATUS, &mut is_linked);
+            if is_linked == gl::FALSE as i32 {
+                let mut length = 0;
+                gl::GetProgramiv(self.resource(), gl::INFO_LOG_LENGTH, &mut length);
+                let mut log = vec![0u8; length.max(1) as usize];
+                gl::GetProgramInfoLog(self.resource(), log.len() as i32, &mut length, log.as_mut_ptr() as *mut i8);
+                log.truncate(length as usize);
+                return Err(String::from_utf8_lossy(&log).into_owned())
+            }
         }
+        Ok(())
     }
 
-    /// Binds a `bool` to the specified `index`.
-    pub fn bind_bool(&self, value: bool, index: usize) {
+    /// Gets the binary format and the driver specific binary of the linked `Program`. It's only
+    /// available if the `Program` was built with `ProgramBuilder::with_retrievable_binary`.
+    pub fn binary(&self) -> Option<(u32, Vec<u8>)> {
         unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform1i(index as i32, value as i32);
+            let mut length = 0;
+            gl::GetProgramiv(self.resource(), gl::PROGRAM_BINARY_LENGTH, &mut length);
+            if length <= 0 {
+                return None
+            }
+            let mut binary = vec![0u8; length as usize];
+            let mut format = 0;
+            gl::GetProgramBinary(self.resource(), length, &mut length, &mut format, binary.as_mut_ptr() as *mut _);
+            binary.truncate(length as usize);
+            Some((format, binary))
         }
     }
 
-    /// Binds a `bvec2` to the specified `index`.
-    pub fn bind_bvec2(&self, value: (bool, bool), index: usize) {
+    /// Creates a `Program` from a binary returned by `binary`. Drivers reject binaries created by
+    /// other drivers or driver versions.
+    pub fn from_binary(context: &Context, format: u32, binary: &[u8]) -> Result<Self, String> {
+        let program = Self::new(context);
         unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform2i(index as i32, value.0 as i32, value.1 as i32);
+            gl::ProgramBinary(program.resource(), format, binary.as_ptr() as *const _, binary.len() as i32);
+            let mut is_linked = gl::FALSE as i32;
+            gl::GetProgramiv(program.resource(), gl::LINK_STATUS, &mut is_linked);
+            if is_linked == gl::FALSE as i32 {
+                return Err("The program binary was rejected by the driver".to_string())
+            }
         }
+        Ok(program)
     }
 
-    /// Binds a `bvec3` to the specified `index`.
-    pub fn bind_bvec3(&self, value: (bool, bool, bool), index: usize) {
+    /// Checks if the `Program` was linked as separable and can be used in a `ProgramPipeline`.
+    pub fn is_separable(&self) -> bool {
+        let mut separable = gl::FALSE as i32;
         unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform3i(index as i32, value.0 as i32, value.1 as i32, value.2 as i32);
+            gl::GetProgramiv(self.resource(), gl::PROGRAM_SEPARABLE, &mut separable);
         }
+        separable != gl::FALSE as i32
     }
 
-    /// Binds a `bvec4` to the specified `index`.
-    pub fn bind_bvec4(&self, value: (bool, bool, bool, bool), index: usize) {
-        unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform4i(index as i32, value.0 as i32, value.1 as i32, value.2 as i32, value.3 as i32);
-        }
+    /// Queries the active uniforms, vertex inputs, fragment outputs and blocks of the linked
+    /// `Program`.
+    pub fn reflect(&self) -> ProgramReflection {
+        ProgramReflection::new(self)
     }
 
-    /// Binds a `f32` to the specified `index`.
-    pub fn bind_f32(&self, value: f32, index: usize) {
-        unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform1f(index as i32, value);
-        }
+    /// Sets whether `set` should silently ignore uniforms that aren't active, e.g. because the
+    /// shader compiler optimized them out. Type mismatches are still reported.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
     }
 
-    /// Binds a `vec2` to the specified `index`.
-    pub fn bind_vec2(&self, value:(f32, f32), index: usize) {
-        unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform2f(index as i32, value.0, value.1);
+    /// Checks if `set` ignores uniforms that aren't active.
+    pub fn is_lenient(&self) -> bool { self.lenient }
+
+    /// Sets `value` to the uniform called `name`, which may be an array element such as
+    /// `lights[2]`. Arrays are set from that element on and can't be longer than the elements
+    /// left. The location is looked up once and cached.
+    pub fn set<U: Uniform + ?Sized>(&self, name: &str, value: &U) -> Result<(), String> {
+        match self.uniform_location(name)? {
+            Some(uniform) => {
+                if !uniform.uniform_type.accepts(value.uniform_type()) {
+                    return Err(format!("Uniform `{}` is of type {:?}, but the value is of type {:?}", name, uniform.uniform_type, value.uniform_type()))
+                }
+                match (value.array_len(), uniform.array_len) {
+                    (Some(_), None) => return Err(format!("Uniform `{}` isn't an array", name)),
+                    (Some(len), Some(array_len)) if len > array_len => {
+                        return Err(format!("{} elements don't fit in the {} elements of uniform `{}`", len, array_len, name))
+                    },
+                    _ => ()
+                }
+                self.set_uniform(uniform.location, value);
+                Ok(())
+            },
+            None if self.lenient => Ok(()),
+            None => Err(format!("Uniform `{}` isn't active", name))
         }
     }
 
-    /// Binds a `vec3` to the specified `index`.
-    pub fn bind_vec3(&self, value: (f32, f32, f32), index: usize) {
+    /// Sets `value` to the uniform at `location`. Arrays are set to consecutive locations.
+    pub fn set_uniform<U: Uniform + ?Sized>(&self, location: usize, value: &U) {
         unsafe {
             gl::UseProgram(self.resource());
-            gl::Uniform3f(index as i32, value.0, value.1, value.2);
         }
+        value.set_uniform(location);
     }
 
-    /// Binds a `vec4` to the specified `index`.
-    pub fn bind_vec4(&self, value: (f32, f32, f32, f32), index: usize) {
-        unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform4f(index as i32, value.0, value.1, value.2, value.3);
+    fn uniform_location(&self, name: &str) -> Result<Option<UniformLocation>, String> {
+        if let Some(location) = self.locations.borrow().get(name) {
+            return Ok(*location)
         }
+        let c_name = CString::new(name).map_err(|_| format!("Uniform name `{}` contains a nul character", name))?;
+        // Array elements such as `lights[2]` have a location, but only the array has a type.
+        let (base_name, element) = match name.rfind('[') {
+            Some(bracket) if name.ends_with(']') => {
+                let element = name[bracket + 1..name.len() - 1].parse::<usize>()
+                    .map_err(|_| format!("Uniform name `{}` has an invalid array index", name))?;
+                (&name[..bracket], Some(element))
+            },
+            _ => (name, None)
+        };
+        let c_base_name = CString::new(base_name).map_err(|_| format!("Uniform name `{}` contains a nul character", name))?;
+        // Arrays are reflected as their first element, so a name without it is only an array if
+        // the name with it is found.
+        let c_first_name = CString::new(format!("{}[0]", base_name)).map_err(|_| format!("Uniform name `{}` contains a nul character", name))?;
+        let location = unsafe {
+            let location = gl::GetProgramResourceLocation(self.resource(), gl::UNIFORM, c_name.as_ptr());
+            let index = gl::GetProgramResourceIndex(self.resource(), gl::UNIFORM, c_base_name.as_ptr());
+            if location < 0 || index == gl::INVALID_INDEX {
+                None
+            } else {
+                let properties = [gl::TYPE, gl::ARRAY_SIZE];
+                let mut values = [0; 2];
+                gl::GetProgramResourceiv(self.resource(), gl::UNIFORM, index, properties.len() as i32, properties.as_ptr(), values.len() as i32, std::ptr::null_mut(), values.as_mut_ptr());
+                let is_array = element.is_some() || gl::GetProgramResourceIndex(self.resource(), gl::UNIFORM, c_first_name.as_ptr()) != gl::INVALID_INDEX;
+                let array_len = if is_array {
+                    Some((values[1] as usize).saturating_sub(element.unwrap_or(0)))
+                } else {
+                    None
+                };
+                let location = location as usize;
+                let uniform_type = UniformType::from_internal(values[0] as u32);
+                Some(UniformLocation { location, uniform_type, array_len })
+            }
+        };
+        self.locations.borrow_mut().insert(name.to_string(), location);
+        Ok(location)
     }
 
-    // FIXME: This function is not safe. v needs to have 4 * 4 = 16 f32s.
-    /// Binds a `mat4` to the specified `index` and determine if it should be `transpose`d.
-    pub fn uniform_mat4(&mut self, location: usize, transpose: bool, v: &[f32]) {
-        unsafe {
-            gl::UseProgram(self.resource());
-            gl::UniformMatrix4fv(location as i32, 1, transpose as u8, v.as_ptr());
+    /// Assigns the binding points of `WgslBindings` to the blocks and samplers of the linked
+    /// `Program`. It's only needed for `GlslDialect`s without `layout(binding = ...)`, but it's
+    /// harmless otherwise. Resources the `Program` doesn't use are skipped.
+    #[cfg(feature = "wgsl")]
+    pub fn apply_bindings(&self, bindings: &WgslBindings) -> Result<(), String> {
+        for binding in bindings.bindings() {
+            let unit = match binding.unit {
+                Some(unit) => unit,
+                None => continue
+            };
+            for glsl_name in &binding.glsl_names {
+                let c_name = CString::new(glsl_name.as_str()).map_err(|_| format!("Name `{}` contains a nul character", glsl_name))?;
+                unsafe {
+                    match binding.kind {
+                        BindingKind::UniformBuffer => {
+                            let index = gl::GetUniformBlockIndex(self.resource(), c_name.as_ptr());
+                            if index != gl::INVALID_INDEX {
+                                gl::UniformBlockBinding(self.resource(), index, unit as u32);
+                            }
+                        },
+                        BindingKind::StorageBuffer => {
+                            let index = gl::GetProgramResourceIndex(self.resource(), gl::SHADER_STORAGE_BLOCK, c_name.as_ptr());
+                            if index != gl::INVALID_INDEX {
+                                gl::ShaderStorageBlockBinding(self.resource(), index, unit as u32);
+                            }
+                        },
+                        BindingKind::Texture | BindingKind::StorageImage => {
+                            let location = gl::GetUniformLocation(self.resource(), c_name.as_ptr());
+                            if location >= 0 {
+                                gl::UseProgram(self.resource());
+                                gl::Uniform1i(location, unit as i32);
+                            }
+                        },
+                        BindingKind::Sampler => ()
+                    }
+                }
+            }
         }
+        Ok(())
     }
 
-    /// Binds an `i32` to the specified `index`.
-    pub fn bind_i32(&self, value: i32, index: usize) {
+// FIXME: These parts were removed because glow uses a minimum set of GL x GLES x WEBGL.
+// These functions can be included in a trait which can be implemented for backends that supports it.
+//    fn bind_buffer(&mut self, buffer: &Buffer, index: u32) {
+//        unsafe {
+//            self::BindBufferBase(gl::SHADER_STORAGE_BUFFER, index, buffer.get_id());
+//        }
+//    }
+
+    //FIXME: Create Sampler1D, Sampler2D and Sampler2D? What would be the benefits of strong types here?
+    /// Binds a `Sampler` at `index`.
+    pub fn bind_sampler(&self, sampler:&Sampler, index: usize) {
         unsafe {
+            gl::ActiveTexture(gl::TEXTURE0 + index as u32);
+            gl::BindTexture(sampler.image.type_(), sampler.image.internal());
+            gl::BindSampler(index as u32, sampler.internal());
             gl::UseProgram(self.resource());
-            gl::Uniform1i(index as i32, value);
+            gl::Uniform1i(index as i32, index as i32);
         }
     }
 
-    /// Binds an `ivec2` to the specified `index`.
-    pub fn bind_ivec2(&self, value: (i32, i32), index: usize) {
+    /// Binds a `BufferRange` of a `Buffer` as the uniform block at the specified `index`.
+    pub fn bind_uniform_buffer(&self, buffer: &Buffer, range: BufferRange, index: usize) {
         unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform2i(index as i32, value.0, value.1);
+            gl::BindBufferRange(gl::UNIFORM_BUFFER, index as u32, buffer.resource(), range.offset as isize, range.size as isize);
         }
     }
 
-    /// Binds an `ivec3` to the specified `index`.
-    pub fn bind_ivec3(&self, value: (i32, i32, i32), index: usize) {
+    /// Binds a 2D `image` to the specified `index`. It fails if the format of the `image` can't be
+    /// used as a storage image.
+    pub fn bind_image_2d(&self, image: &Image2D, index: usize) -> Result<(), String> {
+        self.bind_storage_image(image, index, &StorageImageBinding::new())?;
         unsafe {
             gl::UseProgram(self.resource());
-            gl::Uniform3i(index as i32, value.0, value.1, value.2);
+            gl::Uniform1i(index as i32, index as i32);
         }
+        Ok(())
     }
 
-    /// Binds an `ivec4` to the specified `index`.
-    pub fn bind_ivec4(&self, ivec4: (i32, i32, i32, i32), index: usize) {
+    /// Binds an `Image` of any kind to the image `unit` as described by the
+    /// `StorageImageBinding`. The shader selects the unit with `layout(binding = unit)`.
+    pub fn bind_storage_image(&self, image: &Image, unit: usize, binding: &StorageImageBinding) -> Result<(), String> {
+        let format = binding.format().unwrap_or(*image.format());
+        let es = GlslDialect::from_version_string(&ContextInfo::query().shading_language_version).is_es();
+        if !format.is_storage_compatible(es) {
+            return Err(if es {
+                "Only r32f, r32i, r32ui and four component formats can be used as storage images in OpenGL ES".to_string()
+            } else {
+                "Three component formats can't be used as storage images".to_string()
+            })
+        }
+        if format.texel_size() != image.format().texel_size() {
+            return Err(format!("The format override has {} bytes per texel, but the image has {}", format.texel_size(), image.format().texel_size()))
+        }
+        let (layered, layer) = match binding.layer() {
+            Some(layer) => (gl::FALSE, layer),
+            None => (gl::TRUE, 0)
+        };
         unsafe {
-            gl::UseProgram(self.resource());
-            gl::Uniform4i(index as i32, ivec4.0, ivec4.1, ivec4.2, ivec4.3);
+            gl::BindImageTexture(unit as u32, image.internal(), binding.level() as i32, layered, layer as i32, binding.access().internal(), format.internal_format());
         }
-    }
-
-    /// Binds a 2D `image` to the specified `index`.
-    pub fn bind_image_2d(&self, image: &Image2D, index: usize) {
-       unsafe {
-           gl::UseProgram(self.resource());
-           gl::ActiveTexture(gl::TEXTURE0 + index as u32);
-           gl::BindTexture(image.type_(), image.internal());
-           gl::BindImageTexture(index as u32, image.internal(), 0, gl::FALSE, 0, gl::READ_WRITE, image.format().internal_format());
-           gl::Uniform1i(index as i32, index as i32);
-       }
+        Ok(())
     }
 }
 
@@ -0,0 +1,204 @@
+use crate::{Context, Program, RasterProgram, ComputeProgram, Shader, ShaderStage, FRAG_COLOR};
+use std::ffi::CString;
+
+/// How transform feedback varyings are written to buffers.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum TransformFeedbackMode {
+    /// All varyings are written to a single buffer.
+    Interleaved,
+    /// Each varying is written to its own buffer.
+    Separate
+}
+
+impl TransformFeedbackMode {
+    /// Gets `OpenGL` internal enumeration.
+    pub fn internal(&self) -> u32 {
+        match self {
+            TransformFeedbackMode::Interleaved => gl::INTERLEAVED_ATTRIBS,
+            TransformFeedbackMode::Separate    => gl::SEPARATE_ATTRIBS
+        }
+    }
+}
+
+/// A builder that links any valid combination of `Shader` stages into a `Program`.
+pub struct ProgramBuilder<'a> {
+    shaders                   : Vec<&'a Shader>,
+    attribute_locations       : Vec<(String, usize)>,
+    fragment_output_locations : Vec<(String, usize)>,
+    feedback_varyings         : Vec<String>,
+    feedback_mode             : TransformFeedbackMode,
+    separable                 : bool,
+    retrievable_binary        : bool
+}
+
+impl<'a> Default for ProgramBuilder<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> ProgramBuilder<'a> {
+    /// Creates a new `ProgramBuilder` without shaders.
+    pub fn new() -> Self {
+        let shaders                   = Vec::new();
+        let attribute_locations       = Vec::new();
+        let fragment_output_locations = Vec::new();
+        let feedback_varyings         = Vec::new();
+        let feedback_mode             = TransformFeedbackMode::Interleaved;
+        let separable                 = false;
+        let retrievable_binary        = false;
+        Self { shaders, attribute_locations, fragment_output_locations, feedback_varyings, feedback_mode, separable, retrievable_binary }
+    }
+
+    /// Attaches a `Shader` of any stage.
+    pub fn with_shader(mut self, shader: &'a Shader) -> Self {
+        self.shaders.push(shader);
+        self
+    }
+
+    /// Binds the vertex input `name` to the attribute `location`.
+    pub fn with_attribute_location(mut self, name: &str, location: usize) -> Self {
+        self.attribute_locations.push((name.to_string(), location));
+        self
+    }
+
+    /// Binds the fragment output `name` to the color attachment `location`.
+    pub fn with_fragment_output_location(mut self, name: &str, location: usize) -> Self {
+        self.fragment_output_locations.push((name.to_string(), location));
+        self
+    }
+
+    /// Captures the `varyings` of the last vertex processing stage with transform feedback.
+    pub fn with_transform_feedback_varyings(mut self, varyings: &[&str], mode: TransformFeedbackMode) -> Self {
+        self.feedback_varyings = varyings.iter().map(|varying| varying.to_string()).collect();
+        self.feedback_mode = mode;
+        self
+    }
+
+    /// Links a separable program, whose stages can be combined with other separable programs in a
+    /// `ProgramPipeline`. Separable programs don't need a vertex stage.
+    pub fn with_separable(mut self, separable: bool) -> Self {
+        self.separable = separable;
+        self
+    }
+
+    /// Hints the driver to keep the linked binary, so it can be stored with `Program::binary`.
+    pub fn with_retrievable_binary(mut self, retrievable_binary: bool) -> Self {
+        self.retrievable_binary = retrievable_binary;
+        self
+    }
+
+    /// Gets the stages of the attached shaders, sorted in pipeline order.
+    pub fn stages(&self) -> Vec<ShaderStage> {
+        let mut stages : Vec<ShaderStage> = self.shaders.iter().map(|shader| shader.stage()).collect();
+        stages.sort();
+        stages
+    }
+
+    /// Checks if the attached stages form a valid pipeline.
+    pub fn validate(&self) -> Result<(), String> {
+        let stages = self.stages();
+        if stages.is_empty() {
+            return Err("A program needs at least one shader".to_string())
+        }
+        if let Some(stage) = stages.windows(2).find(|pair| pair[0] == pair[1]).map(|pair| pair[0]) {
+            return Err(format!("The {} stage is attached more than once", stage.name()))
+        }
+        let has = |stage| stages.contains(&stage);
+        if has(ShaderStage::Compute) && stages.len() > 1 {
+            return Err("The compute stage can't be linked with other stages".to_string())
+        }
+        if !self.separable && !has(ShaderStage::Compute) && !has(ShaderStage::Vertex) {
+            return Err("A raster program needs a vertex stage".to_string())
+        }
+        if has(ShaderStage::TessControl) && !has(ShaderStage::TessEvaluation) {
+            return Err("The tessellation control stage needs a tessellation evaluation stage".to_string())
+        }
+        Ok(())
+    }
+
+    /// Links the attached shaders into a `Program`. Link errors are grouped by the stage they
+    /// refer to.
+    pub fn build(&self, context: &Context) -> Result<Program, String> {
+        self.validate()?;
+        let program = Program::new(context);
+        let c_string = |name: &str| CString::new(name).map_err(|_| format!("`{}` contains a nul character", name));
+        unsafe {
+            if self.separable {
+                gl::ProgramParameteri(program.resource(), gl::PROGRAM_SEPARABLE, gl::TRUE as i32);
+            }
+            if self.retrievable_binary {
+                gl::ProgramParameteri(program.resource(), gl::PROGRAM_BINARY_RETRIEVABLE_HINT, gl::TRUE as i32);
+            }
+            for shader in self.shaders.iter() {
+                gl::AttachShader(program.resource(), shader.resource());
+            }
+            for (name, location) in self.attribute_locations.iter() {
+                gl::BindAttribLocation(program.resource(), *location as u32, c_string(name)?.as_ptr());
+            }
+            // Adapted sources below GLSL 3.30 declare `gl_FragColor`'s replacement without a layout.
+            if gl::BindFragDataLocation::is_loaded() && !self.fragment_output_locations.iter().any(|(name, _)| name == FRAG_COLOR) {
+                gl::BindFragDataLocation(program.resource(), 0, c_string(FRAG_COLOR)?.as_ptr());
+            }
+            for (name, location) in self.fragment_output_locations.iter() {
+                gl::BindFragDataLocation(program.resource(), *location as u32, c_string(name)?.as_ptr());
+            }
+            if !self.feedback_varyings.is_empty() {
+                let varyings = self.feedback_varyings.iter().map(|varying| c_string(varying)).collect::<Result<Vec<_>, _>>()?;
+                let pointers : Vec<*const i8> = varyings.iter().map(|varying| varying.as_ptr()).collect();
+                gl::TransformFeedbackVaryings(program.resource(), pointers.len() as i32, pointers.as_ptr(), self.feedback_mode.internal());
+            }
+        }
+        let linked = program.link();
+        unsafe {
+            for shader in self.shaders.iter() {
+                gl::DetachShader(program.resource(), shader.resource());
+            }
+        }
+        linked.map_err(|log| self.group_log(&log))?;
+        Ok(program)
+    }
+
+    /// Links the attached shaders into a `RasterProgram`.
+    pub fn build_raster(&self, context: &Context) -> Result<RasterProgram, String> {
+        if !self.stages().contains(&ShaderStage::Vertex) {
+            return Err("A raster program needs a vertex stage".to_string())
+        }
+        let program = self.build(context)?;
+        Ok(RasterProgram { program })
+    }
+
+    /// Links the attached shaders into a `ComputeProgram`.
+    pub fn build_compute(&self, context: &Context) -> Result<ComputeProgram, String> {
+        if !self.stages().contains(&ShaderStage::Compute) {
+            return Err("A compute program needs a compute stage".to_string())
+        }
+        let program = self.build(context)?;
+        Ok(ComputeProgram::from_program(program))
+    }
+
+    /// Groups the lines of a link log under the attached stage they mention.
+    fn group_log(&self, log: &str) -> String {
+        let stages = self.stages();
+        let mut groups : Vec<(Option<ShaderStage>, Vec<&str>)> = Vec::new();
+        for line in log.lines().filter(|line| !line.trim().is_empty()) {
+            let lowercase = line.to_lowercase().replace("tess ", "tessellation ");
+            let stage = stages.iter().cloned().find(|stage| lowercase.contains(stage.name()));
+            match groups.iter_mut().find(|(group, _)| *group == stage) {
+                Some((_, lines)) => lines.push(line),
+                None => groups.push((stage, vec![line]))
+            }
+        }
+        groups.sort_by_key(|(stage, _)| *stage);
+        let mut message = String::from("Failed to link program");
+        for (stage, lines) in groups {
+            let name = stage.map(|stage| stage.name()).unwrap_or("program");
+            message.push_str(&format!("\n[{}]", name));
+            for line in lines {
+                message.push_str("\n    ");
+                message.push_str(line.trim());
+            }
+        }
+        message
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{Context, ContextInfo, Program, ProgramBuilder, Shader, ShaderStage, PreprocessedSource};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An on-disk cache of linked `Program` binaries, keyed by the shader sources and the driver.
+pub struct ProgramCache {
+    directory : PathBuf
+}
+
+impl ProgramCache {
+    /// Creates a `ProgramCache` storing its entries in `directory`, which is created if needed.
+    pub fn new(directory: impl Into<PathBuf>) -> Result<Self, String> {
+        let directory = directory.into();
+        fs::create_dir_all(&directory)
+            .map_err(|error| format!("Couldn't create the cache directory {}: {}", directory.display(), error))?;
+        Ok(Self { directory })
+    }
+
+    /// Gets the directory where the entries are stored.
+    pub fn directory(&self) -> &Path { &self.directory }
+
+    /// Computes the key of a program made of the `sources` of each stage on the driver described
+    /// by `info`. Defines must already be applied to the sources.
+    pub fn key(&self, info: &ContextInfo, sources: &[(ShaderStage, &str)]) -> u64 {
+        let mut hash = Fnv::new();
+        hash.write(info.vendor.as_bytes());
+        hash.write(info.renderer.as_bytes());
+        hash.write(info.version.as_bytes());
+        hash.write(info.shading_language_version.as_bytes());
+        for (stage, source) in sources.iter() {
+            hash.write(stage.name().as_bytes());
+            hash.write(source.as_bytes());
+        }
+        hash.finish()
+    }
+
+    /// Loads the `Program` stored with `key`. Entries rejected by the driver are removed.
+    pub fn load(&self, context: &Context, key: u64) -> Option<Program> {
+        let path = self.path(key);
+        let data = fs::read(&path).ok()?;
+        let program = if data.len() > 4 {
+            let format = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
+            Program::from_binary(context, format, &data[4..]).ok()
+        } else {
+            None
+        };
+        if program.is_none() {
+            let _ = fs::remove_file(&path);
+        }
+        program
+    }
+
+    /// Stores the binary of a `Program` built with `ProgramBuilder::with_retrievable_binary`.
+    pub fn store(&self, key: u64, program: &Program) -> Result<(), String> {
+        let (format, binary) = program.binary().ok_or_else(|| "The program binary isn't retrievable".to_string())?;
+        let mut data = Vec::with_capacity(4 + binary.len());
+        data.extend_from_slice(&format.to_le_bytes());
+        data.extend_from_slice(&binary);
+        // Writing to a temporary file first, so a concurrent `load` never reads a partial entry.
+        let path = self.path(key);
+        let temporary = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&temporary, data).map_err(|error| format!("Couldn't write {}: {}", temporary.display(), error))?;
+        fs::rename(&temporary, &path).map_err(|error| {
+            let _ = fs::remove_file(&temporary);
+            format!("Couldn't write {}: {}", path.display(), error)
+        })
+    }
+
+    /// Loads the `Program` made of the `sources` of each stage, or compiles, links and stores it
+    /// if it isn't cached or its binary is rejected. The sources come from a `Preprocessor`, so
+    /// the key covers their defines.
+    pub fn program(&self, context: &Context, sources: &[(ShaderStage, &PreprocessedSource)]) -> Result<Program, String> {
+        let texts : Vec<(ShaderStage, &str)> = sources.iter().map(|(stage, source)| (*stage, source.source())).collect();
+        let key = self.key(&context.info(), &texts);
+        if let Some(program) = self.load(context, key) {
+            return Ok(program)
+        }
+        let shaders = sources.iter()
+            .map(|(stage, source)| Shader::new(context, stage.internal(), *source))
+            .collect::<Result<Vec<_>, _>>()?;
+        let builder = shaders.iter().fold(ProgramBuilder::new(), |builder, shader| builder.with_shader(shader));
+        let program = builder.with_retrievable_binary(true).build(context)?;
+        // A failure to store only costs a recompilation in the next run.
+        let _ = self.store(key, &program);
+        Ok(program)
+    }
+
+    /// Removes all the entries and the temporary files left by interrupted `store`s.
+    pub fn clear(&self) -> Result<(), String> {
+        let entries = fs::read_dir(&self.directory).map_err(|error| error.to_string())?;
+        for entry in entries.filter_map(|entry| entry.ok()) {
+            let path = entry.path();
+            if path.extension().map(|extension| extension == "bin" || extension == "tmp").unwrap_or(false) {
+                fs::remove_file(&path).map_err(|error| error.to_string())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn path(&self, key: u64) -> PathBuf {
+        self.directory.join(format!("{:016x}.bin", key))
+    }
+}
+
+/// 64 bits FNV-1a hash, which is stable across runs and Rust versions unlike `DefaultHasher`.
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Self {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        // The trailing separator keeps ("ab", "c") and ("a", "bc") apart.
+        for byte in bytes.iter().chain(std::iter::once(&0xff)) {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{Context, GLContext, Program, Raster, ShaderStage};
+
+type ProgramPipelineResource = u32;
+
+/// A pipeline combining the stages of separable `Program`s at draw time.
+pub struct ProgramPipeline {
+    _gl      : GLContext,
+    resource : ProgramPipelineResource
+}
+
+impl ProgramPipeline {
+    /// Creates a new `ProgramPipeline` without stages.
+    pub fn new(context: &Context) -> Self {
+        let gl = context.gl_context();
+        let resource = unsafe {
+            let mut resource = 0;
+            gl::GenProgramPipelines(1, &mut resource);
+            resource
+        };
+        Self { _gl: gl, resource }
+    }
+
+    /// Gets the `ProgramPipelineResource`.
+    pub fn resource(&self) -> ProgramPipelineResource { self.resource }
+
+    /// Uses the `stages` of a separable `Program`, replacing the programs previously used for
+    /// them. The `Program` must outlive its use in the pipeline.
+    pub fn use_program_stages(&mut self, program: &Program, stages: &[ShaderStage]) -> Result<(), String> {
+        if !program.is_separable() {
+            return Err("Only separable programs can be used in a ProgramPipeline".to_string())
+        }
+        let bits = stages.iter().fold(0, |bits, stage| bits | Self::stage_bit(*stage));
+        unsafe {
+            gl::UseProgramStages(self.resource(), bits, program.resource());
+        }
+        Ok(())
+    }
+
+    /// Removes the program used for the `stages`.
+    pub fn clear_stages(&mut self, stages: &[ShaderStage]) {
+        let bits = stages.iter().fold(0, |bits, stage| bits | Self::stage_bit(*stage));
+        unsafe {
+            gl::UseProgramStages(self.resource(), bits, 0);
+        }
+    }
+
+    /// Checks if the stages' interfaces match and the pipeline can be used for drawing.
+    pub fn validate(&self) -> Result<(), String> {
+        unsafe {
+            gl::ValidateProgramPipeline(self.resource());
+            let mut is_valid = gl::FALSE as i32;
+            gl::GetProgramPipelineiv(self.resource(), gl::VALIDATE_STATUS, &mut is_valid);
+            if is_valid == gl::FALSE as i32 {
+                let mut length = 0;
+                gl::GetProgramPipelineiv(self.resource(), gl::INFO_LOG_LENGTH, &mut length);
+                let mut log = vec![0u8; length.max(1) as usize];
+                gl::GetProgramPipelineInfoLog(self.resource(), log.len() as i32, &mut length, log.as_mut_ptr() as *mut i8);
+                log.truncate(length as usize);
+                return Err(String::from_utf8_lossy(&log).into_owned())
+            }
+        }
+        Ok(())
+    }
+
+    fn stage_bit(stage: ShaderStage) -> u32 {
+        match stage {
+            ShaderStage::Vertex         => gl::VERTEX_SHADER_BIT,
+            ShaderStage::TessControl    => gl::TESS_CONTROL_SHADER_BIT,
+            ShaderStage::TessEvaluation => gl::TESS_EVALUATION_SHADER_BIT,
+            ShaderStage::Geometry       => gl::GEOMETRY_SHADER_BIT,
+            ShaderStage::Fragment       => gl::FRAGMENT_SHADER_BIT,
+            ShaderStage::Compute        => gl::COMPUTE_SHADER_BIT
+        }
+    }
+}
+
+impl Raster for ProgramPipeline {
+    fn bind_stages(&self) {
+        unsafe {
+            gl::UseProgram(0);
+            gl::BindProgramPipeline(self.resource());
+        }
+    }
+}
+
+impl Drop for ProgramPipeline {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteProgramPipelines(1, &self.resource);
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{Program, UniformType, VertexArrayObject, AttributeKind};
+
+/// An active uniform of a `Program`.
+#[derive(Clone,Debug,PartialEq)]
+pub struct UniformInfo {
+    /// Name. Arrays end with `[0]`.
+    pub name        : String,
+    /// GLSL type.
+    pub type_       : UniformType,
+    /// Number of elements. It's `1` if it isn't an array.
+    pub array_size  : usize,
+    /// Location. It's `None` for members of uniform blocks.
+    pub location    : Option<usize>,
+    /// Index of the uniform block in `ProgramReflection::uniform_blocks` it belongs to.
+    pub block_index : Option<usize>,
+    /// Offset in bytes in the uniform block it belongs to.
+    pub offset      : Option<usize>,
+    /// Texture or image unit of samplers and images.
+    pub binding     : Option<usize>
+}
+
+/// An active vertex input or fragment output of a `Program`.
+#[derive(Clone,Debug,PartialEq)]
+pub struct VariableInfo {
+    /// Name. Arrays end with `[0]`.
+    pub name       : String,
+    /// GLSL type.
+    pub type_      : UniformType,
+    /// Number of elements. It's `1` if it isn't an array.
+    pub array_size : usize,
+    /// Location. It's `None` for built-in variables.
+    pub location   : Option<usize>
+}
+
+/// A member of a uniform or storage block.
+#[derive(Clone,Debug,PartialEq)]
+pub struct BlockMemberInfo {
+    /// Name, qualified with the block name if the block has an instance name.
+    pub name          : String,
+    /// GLSL type.
+    pub type_         : UniformType,
+    /// Offset in bytes from the beginning of the block.
+    pub offset        : usize,
+    /// Number of elements. It's `1` if it isn't an array and `0` for unsized arrays.
+    pub array_size    : usize,
+    /// Distance in bytes between two consecutive array elements.
+    pub array_stride  : usize,
+    /// Distance in bytes between two consecutive matrix columns or rows.
+    pub matrix_stride : usize
+}
+
+/// An active uniform or storage block of a `Program`.
+#[derive(Clone,Debug,PartialEq)]
+pub struct BlockInfo {
+    /// Name.
+    pub name    : String,
+    /// Buffer binding point.
+    pub binding : usize,
+    /// Minimum size in bytes of the bound `Buffer`.
+    pub size    : usize,
+    /// Active members.
+    pub members : Vec<BlockMemberInfo>
+}
+
+/// The active interface of a linked `Program`.
+#[derive(Clone,Debug,PartialEq)]
+pub struct ProgramReflection {
+    /// Uniforms, including the members of uniform blocks.
+    pub uniforms       : Vec<UniformInfo>,
+    /// Vertex shader inputs.
+    pub inputs         : Vec<VariableInfo>,
+    /// Fragment shader outputs.
+    pub outputs        : Vec<VariableInfo>,
+    /// Uniform blocks.
+    pub uniform_blocks : Vec<BlockInfo>,
+    /// Shader storage blocks.
+    pub storage_blocks : Vec<BlockInfo>
+}
+
+impl ProgramReflection {
+    /// Queries the active interface of a linked `Program`.
+    pub fn new(program: &Program) -> Self {
+        let resource       = program.resource();
+        let uniforms       = Self::uniforms(resource);
+        let inputs         = Self::variables(resource, gl::PROGRAM_INPUT);
+        let outputs        = Self::variables(resource, gl::PROGRAM_OUTPUT);
+        let uniform_blocks = Self::blocks(resource, gl::UNIFORM_BLOCK, gl::UNIFORM);
+        let storage_blocks = Self::blocks(resource, gl::SHADER_STORAGE_BLOCK, gl::BUFFER_VARIABLE);
+        Self { uniforms, inputs, outputs, uniform_blocks, storage_blocks }
+    }
+
+    /// Gets the uniform with `name`. The `[0]` suffix of arrays is optional.
+    pub fn uniform(&self, name: &str) -> Option<&UniformInfo> {
+        self.uniforms.iter().find(|uniform| Self::name_matches(&uniform.name, name))
+    }
+
+    /// Gets the vertex input with `name`.
+    pub fn input(&self, name: &str) -> Option<&VariableInfo> {
+        self.inputs.iter().find(|input| Self::name_matches(&input.name, name))
+    }
+
+    /// Gets the fragment output with `name`.
+    pub fn output(&self, name: &str) -> Option<&VariableInfo> {
+        self.outputs.iter().find(|output| Self::name_matches(&output.name, name))
+    }
+
+    /// Gets the sampler uniforms.
+    pub fn samplers(&self) -> Vec<&UniformInfo> {
+        self.uniforms.iter().filter(|uniform| uniform.type_.is_sampler()).collect()
+    }
+
+    /// Gets the image uniforms.
+    pub fn images(&self) -> Vec<&UniformInfo> {
+        self.uniforms.iter().filter(|uniform| uniform.type_.is_image()).collect()
+    }
+
+    /// Checks if every vertex input has an attribute in the `VertexArrayObject` and if integer
+    /// inputs are fed by integer attributes and float inputs by float attributes.
+    pub fn validate_vertex_array(&self, vertex_array_object: &VertexArrayObject) -> Result<(), String> {
+        for input in self.inputs.iter() {
+            let location = match input.location {
+                Some(location) => location,
+                None => continue
+            };
+            let attribute = vertex_array_object.attribute(location)
+                .ok_or_else(|| format!("Vertex input `{}` at location {} doesn't have an attribute", input.name, location))?;
+            let integer = attribute.kind() == AttributeKind::Integer;
+            if integer != input.type_.is_integer() {
+                return Err(format!("Vertex input `{}` of type {:?} at location {} is fed by a {:?} attribute", input.name, input.type_, location, attribute.kind()))
+            }
+        }
+        Ok(())
+    }
+
+    fn name_matches(active: &str, name: &str) -> bool {
+        active == name || (active.ends_with("[0]") && &active[..active.len() - 3] == name)
+    }
+
+    fn n_resources(program: u32, interface: u32) -> usize {
+        let mut n_resources = 0;
+        unsafe {
+            gl::GetProgramInterfaceiv(program, interface, gl::ACTIVE_RESOURCES, &mut n_resources);
+        }
+        n_resources as usize
+    }
+
+    fn name(program: u32, interface: u32, index: usize) -> String {
+        unsafe {
+            let mut max_length = 0;
+            gl::GetProgramInterfaceiv(program, interface, gl::MAX_NAME_LENGTH, &mut max_length);
+            let mut name = vec![0u8; max_length.max(1) as usize];
+            let mut length = 0;
+            gl::GetProgramResourceName(program, interface, index as u32, name.len() as i32, &mut length, name.as_mut_ptr() as *mut i8);
+            name.truncate(length as usize);
+            String::from_utf8_lossy(&name).into_owned()
+        }
+    }
+
+    fn properties(program: u32, interface: u32, index: usize, properties: &[u32]) -> Vec<i32> {
+        let mut values = vec![0; properties.len()];
+        unsafe {
+            gl::GetProgramResourceiv(program, interface, index as u32, properties.len() as i32, properties.as_ptr(),
+                                     values.len() as i32, std::ptr::null_mut(), values.as_mut_ptr());
+        }
+        values
+    }
+
+    fn optional(value: i32) -> Option<usize> {
+        if value < 0 { None } else { Some(value as usize) }
+    }
+
+    fn uniforms(program: u32) -> Vec<UniformInfo> {
+        let properties = [gl::TYPE, gl::ARRAY_SIZE, gl::LOCATION, gl::BLOCK_INDEX, gl::OFFSET];
+        (0..Self::n_resources(program, gl::UNIFORM)).map(|index| {
+            let values      = Self::properties(program, gl::UNIFORM, index, &properties);
+            let name        = Self::name(program, gl::UNIFORM, index);
+            let type_       = UniformType::from_internal(values[0] as u32);
+            let array_size  = values[1] as usize;
+            let location    = Self::optional(values[2]);
+            let block_index = Self::optional(values[3]);
+            let offset      = block_index.and(Self::optional(values[4]));
+            let binding     = match location {
+                Some(location) if type_.is_sampler() || type_.is_image() => unsafe {
+                    let mut unit = 0;
+                    gl::GetUniformiv(program, location as i32, &mut unit);
+                    Some(unit as usize)
+                },
+                _ => None
+            };
+            UniformInfo { name, type_, array_size, location, block_index, offset, binding }
+        }).collect()
+    }
+
+    fn variables(program: u32, interface: u32) -> Vec<VariableInfo> {
+        let properties = [gl::TYPE, gl::ARRAY_SIZE, gl::LOCATION];
+        (0..Self::n_resources(program, interface)).map(|index| {
+            let values     = Self::properties(program, interface, index, &properties);
+            let name       = Self::name(program, interface, index);
+            let type_      = UniformType::from_internal(values[0] as u32);
+            let array_size = values[1] as usize;
+            let location   = Self::optional(values[2]);
+            VariableInfo { name, type_, array_size, location }
+        }).collect()
+    }
+
+    fn blocks(program: u32, interface: u32, member_interface: u32) -> Vec<BlockInfo> {
+        let properties        = [gl::BUFFER_BINDING, gl::BUFFER_DATA_SIZE, gl::NUM_ACTIVE_VARIABLES];
+        let member_properties = [gl::TYPE, gl::OFFSET, gl::ARRAY_SIZE, gl::ARRAY_STRIDE, gl::MATRIX_STRIDE];
+        (0..Self::n_resources(program, interface)).map(|index| {
+            let values  = Self::properties(program, interface, index, &properties);
+            let name    = Self::name(program, interface, index);
+            let binding = values[0] as usize;
+            let size    = values[1] as usize;
+            let mut member_indices = vec![0; values[2] as usize];
+            unsafe {
+                gl::GetProgramResourceiv(program, interface, index as u32, 1, &gl::ACTIVE_VARIABLES,
+                                         member_indices.len() as i32, std::ptr::null_mut(), member_indices.as_mut_ptr());
+            }
+            let mut members : Vec<BlockMemberInfo> = member_indices.iter().map(|member_index| {
+                let member_index  = *member_index as usize;
+                let values        = Self::properties(program, member_interface, member_index, &member_properties);
+                let name          = Self::name(program, member_interface, member_index);
+                let type_         = UniformType::from_internal(values[0] as u32);
+                let offset        = values[1] as usize;
+                let array_size    = values[2] as usize;
+                let array_stride  = values[3] as usize;
+                let matrix_stride = values[4] as usize;
+                BlockMemberInfo { name, type_, offset, array_size, array_stride, matrix_stride }
+            }).collect();
+            members.sort_by_key(|member| member.offset);
+            BlockInfo { name, binding, size, members }
+        }).collect()
+    }
+}
@@ -0,0 +1,196 @@
+use std::mem::size_of;
+
+use crate::VertexArrayObject;
+use crate::IndexRange;
+use crate::IndexType;
+use crate::Framebuffer;
+use crate::Buffer;
+use crate::DrawArraysIndirectCommand;
+use crate::DrawElementsIndirectCommand;
+use crate::IndirectCommands;
+use crate::InstanceRange;
+use crate::RasterGeometry;
+use crate::RasterState;
+use crate::TransformFeedback;
+
+/// Draw calls shared by linked `RasterProgram`s and `ProgramPipeline`s.
+pub trait Raster {
+    /// Makes the program or pipeline current. Used by the draw calls.
+    fn bind_stages(&self);
+
+    /// Draws the `n_vertices` in a `VertexArrayObject` as the specified `RasterGeometry` on the target `Framebuffer` with the `RasterState`.
+    /// Raster calls fail without drawing if the `RasterGeometry` isn't valid.
+    fn raster(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, n_vertices: usize) -> Result<(), String> {
+        prepare(self, framebuffer, state, vertex_array_object, raster_geometry)?;
+        unsafe {
+            gl::DrawArrays(raster_geometry.mode(), 0, n_vertices as i32);
+        }
+        Ok(())
+    }
+
+    /// Draws the `InstanceRange` of the `n_vertices` in a `VertexArrayObject` as the specified
+    /// `RasterGeometry` on the target `Framebuffer` with the `RasterState`.
+    fn raster_instanced(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, n_vertices: usize, instances: InstanceRange) -> Result<(), String> {
+        prepare(self, framebuffer, state, vertex_array_object, raster_geometry)?;
+        unsafe {
+            let mode = raster_geometry.mode();
+            if instances.first == 0 {
+                gl::DrawArraysInstanced(mode, 0, n_vertices as i32, instances.count as i32);
+            } else {
+                gl::DrawArraysInstancedBaseInstance(mode, 0, n_vertices as i32, instances.count as i32, instances.first as u32);
+            }
+        }
+        Ok(())
+    }
+
+    /// Captures the transform feedback varyings of the `n_vertices` in a `VertexArrayObject` drawn
+    /// as the specified `RasterGeometry` into the `Buffer`s of the `TransformFeedback`.
+    /// Rasterization is discarded, so nothing is drawn. The `feedback_geometry` is the kind of
+    /// primitives the last stage outputs and must be `Points`, `Lines` or `Triangles`. Without a
+    /// geometry or tessellation stage, it must match the `RasterGeometry`.
+    fn capture(&self, transform_feedback: &TransformFeedback, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, feedback_geometry: RasterGeometry, n_vertices: usize) -> Result<(), String> {
+        raster_geometry.validate()?;
+        let primitive_mode = feedback_geometry.feedback_mode()
+            .ok_or_else(|| format!("{:?} can't be captured with transform feedback", feedback_geometry))?;
+        self.bind_stages();
+        vertex_array_object.bind();
+        vertex_array_object.bind_primitive_restart();
+        raster_geometry.bind();
+        transform_feedback.begin(primitive_mode);
+        unsafe {
+            gl::DrawArrays(raster_geometry.mode(), 0, n_vertices as i32);
+        }
+        transform_feedback.end();
+        Ok(())
+    }
+
+    /// Draws all the indexed vertices in a `VertexArrayObject` as the specified `RasterGeometry`
+    /// on the target `Framebuffer` with the `RasterState`. Indexed raster calls fail if the
+    /// `VertexArrayObject` doesn't have an index buffer.
+    fn indexed_raster(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry) -> Result<(), String> {
+        let range = vertex_array_object.index_range();
+        self.indexed_raster_range(framebuffer, state, vertex_array_object, raster_geometry, range)
+    }
+
+    /// Draws the indexed vertices in the `IndexRange` of a `VertexArrayObject` as the specified
+    /// `RasterGeometry` on the target `Framebuffer` with the `RasterState`.
+    fn indexed_raster_range(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, range: IndexRange) -> Result<(), String> {
+        let index_type = index_type(vertex_array_object)?;
+        prepare(self, framebuffer, state, vertex_array_object, raster_geometry)?;
+        draw_elements(index_type, raster_geometry, range, InstanceRange::new(0, 1));
+        Ok(())
+    }
+
+    /// Draws the `InstanceRange` of all the indexed vertices in a `VertexArrayObject` as the
+    /// specified `RasterGeometry` on the target `Framebuffer` with the `RasterState`.
+    fn indexed_raster_instanced(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, instances: InstanceRange) -> Result<(), String> {
+        let index_type = index_type(vertex_array_object)?;
+        prepare(self, framebuffer, state, vertex_array_object, raster_geometry)?;
+        draw_elements(index_type, raster_geometry, vertex_array_object.index_range(), instances);
+        Ok(())
+    }
+
+    /// Draws the ranges of indexed vertices in a `VertexArrayObject` as the specified
+    /// `RasterGeometry` on the target `Framebuffer` with the `RasterState` in a single call.
+    fn multi_indexed_raster(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, ranges: &[IndexRange]) -> Result<(), String> {
+        let index_type   = index_type(vertex_array_object)?;
+        prepare(self, framebuffer, state, vertex_array_object, raster_geometry)?;
+        let counts       : Vec<i32> = ranges.iter().map(|range| range.count as i32).collect();
+        let offsets      : Vec<*const std::ffi::c_void> = ranges.iter().map(|range| (range.first * index_type.size()) as *const std::ffi::c_void).collect();
+        let base_vertices: Vec<i32> = ranges.iter().map(|range| range.base_vertex as i32).collect();
+        unsafe {
+            gl::MultiDrawElementsBaseVertex(raster_geometry.mode(), counts.as_ptr(), index_type.format(), offsets.as_ptr(), ranges.len() as i32, base_vertices.as_ptr());
+        }
+        Ok(())
+    }
+
+    /// Draws the vertices in a `VertexArrayObject` as the specified `RasterGeometry` on the target
+    /// `Framebuffer` with the `RasterState`, reading a `DrawArraysIndirectCommand` at `offset`
+    /// bytes of a `Buffer`.
+    fn raster_indirect(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, buffer: &Buffer, offset: usize) -> Result<(), String> {
+        self.multi_raster_indirect(framebuffer, state, vertex_array_object, raster_geometry, IndirectCommands::new(buffer, offset))
+    }
+
+    /// Draws the vertices in a `VertexArrayObject` as the specified `RasterGeometry` on the target
+    /// `Framebuffer` with the `RasterState`, reading the `DrawArraysIndirectCommand`s described by
+    /// the `IndirectCommands`.
+    fn multi_raster_indirect(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, commands: IndirectCommands) -> Result<(), String> {
+        prepare(self, framebuffer, state, vertex_array_object, raster_geometry)?;
+        commands.buffer.bind_to(gl::DRAW_INDIRECT_BUFFER);
+        unsafe {
+            let mode   = raster_geometry.mode();
+            let stride = size_of::<DrawArraysIndirectCommand>() as i32;
+            let offset = commands.offset as *const std::ffi::c_void;
+            if commands.draw_count == 1 {
+                gl::DrawArraysIndirect(mode, offset);
+            } else {
+                gl::MultiDrawArraysIndirect(mode, offset, commands.draw_count as i32, stride);
+            }
+        }
+        Ok(())
+    }
+
+    /// Draws the indexed vertices in a `VertexArrayObject` as the specified `RasterGeometry` on
+    /// the target `Framebuffer` with the `RasterState`, reading a `DrawElementsIndirectCommand`
+    /// at `offset` bytes of a `Buffer`.
+    fn indexed_raster_indirect(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, buffer: &Buffer, offset: usize) -> Result<(), String> {
+        self.multi_indexed_raster_indirect(framebuffer, state, vertex_array_object, raster_geometry, IndirectCommands::new(buffer, offset))
+    }
+
+    /// Draws the indexed vertices in a `VertexArrayObject` as the specified `RasterGeometry` on
+    /// the target `Framebuffer` with the `RasterState`, reading the `DrawElementsIndirectCommand`s
+    /// described by the `IndirectCommands`.
+    fn multi_indexed_raster_indirect(&self, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry, commands: IndirectCommands) -> Result<(), String> {
+        let index_type = index_type(vertex_array_object)?;
+        prepare(self, framebuffer, state, vertex_array_object, raster_geometry)?;
+        commands.buffer.bind_to(gl::DRAW_INDIRECT_BUFFER);
+        unsafe {
+            let mode   = raster_geometry.mode();
+            let stride = size_of::<DrawElementsIndirectCommand>() as i32;
+            let offset = commands.offset as *const std::ffi::c_void;
+            if commands.draw_count == 1 {
+                gl::DrawElementsIndirect(mode, index_type.format(), offset);
+            } else {
+                gl::MultiDrawElementsIndirect(mode, index_type.format(), offset, commands.draw_count as i32, stride);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Binds the `Framebuffer`, the program, the `RasterState` and the `VertexArrayObject` for
+/// rasterizing the specified `RasterGeometry`. Nothing is bound if the `RasterGeometry` isn't
+/// valid.
+fn prepare<R: Raster + ?Sized>(raster: &R, framebuffer: &Framebuffer, state: &RasterState, vertex_array_object: &VertexArrayObject, raster_geometry: RasterGeometry) -> Result<(), String> {
+    raster_geometry.validate()?;
+    framebuffer.bind();
+    raster.bind_stages();
+    state.bind(framebuffer.dimensions());
+    vertex_array_object.bind();
+    vertex_array_object.bind_primitive_restart();
+    raster_geometry.bind();
+    unsafe {
+        gl::Enable(gl::PROGRAM_POINT_SIZE);
+    }
+    Ok(())
+}
+
+fn index_type(vertex_array_object: &VertexArrayObject) -> Result<IndexType, String> {
+    vertex_array_object.index_type().ok_or_else(|| "VertexArrayObject doesn't have an index buffer".to_string())
+}
+
+fn draw_elements(index_type: IndexType, raster_geometry: RasterGeometry, range: IndexRange, instances: InstanceRange) {
+    let mode        = raster_geometry.mode();
+    let count       = range.count as i32;
+    let type_       = index_type.format();
+    let offset      = (range.first * index_type.size()) as *const std::ffi::c_void;
+    let base_vertex = range.base_vertex as i32;
+    unsafe {
+        match (instances.count, instances.first) {
+            (1, 0) if base_vertex == 0 => gl::DrawElements(mode, count, type_, offset),
+            (1, 0) => gl::DrawElementsBaseVertex(mode, count, type_, offset, base_vertex),
+            (_, 0) if base_vertex == 0 => gl::DrawElementsInstanced(mode, count, type_, offset, instances.count as i32),
+            _ => gl::DrawElementsInstancedBaseVertexBaseInstance(mode, count, type_, offset, instances.count as i32, base_vertex, instances.first as u32)
+        }
+    }
+}
@@ -0,0 +1,78 @@
+/// Kinds of raster geometries.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum RasterGeometry {
+    /// Raster each vertex as a point.
+    Points,
+    /// Raster two consecutive vertices as a line.
+    Lines,
+    /// Raster the vertices as a sequence of lines.
+    LineStrip,
+    /// Raster the vertices as a sequence of lines, connecting the last vertex to the first.
+    LineLoop,
+    /// Raster three consecutive vertices as a triangle.
+    Triangles,
+    /// Raster every vertex with the previous two as a triangle.
+    TriangleStrip,
+    /// Raster every vertex with the previous one and the first as a triangle.
+    TriangleFan,
+    /// Raster four consecutive vertices as a line with its two adjacent vertices.
+    LinesAdjacency,
+    /// Raster the vertices as a sequence of lines with their adjacent vertices.
+    LineStripAdjacency,
+    /// Raster six consecutive vertices as a triangle with its three adjacent vertices.
+    TrianglesAdjacency,
+    /// Raster the vertices as a strip of triangles with their adjacent vertices.
+    TriangleStripAdjacency,
+    /// Send the specified number of consecutive vertices as a patch to the tessellation stages.
+    Patches(usize)
+}
+
+impl RasterGeometry {
+    /// Gets `OpenGL` internal representation.
+    pub fn mode(&self) -> u32 {
+        match self {
+            RasterGeometry::Points                 => gl::POINTS,
+            RasterGeometry::Lines                  => gl::LINES,
+            RasterGeometry::LineStrip              => gl::LINE_STRIP,
+            RasterGeometry::LineLoop               => gl::LINE_LOOP,
+            RasterGeometry::Triangles              => gl::TRIANGLES,
+            RasterGeometry::TriangleStrip          => gl::TRIANGLE_STRIP,
+            RasterGeometry::TriangleFan            => gl::TRIANGLE_FAN,
+            RasterGeometry::LinesAdjacency         => gl::LINES_ADJACENCY,
+            RasterGeometry::LineStripAdjacency     => gl::LINE_STRIP_ADJACENCY,
+            RasterGeometry::TrianglesAdjacency     => gl::TRIANGLES_ADJACENCY,
+            RasterGeometry::TriangleStripAdjacency => gl::TRIANGLE_STRIP_ADJACENCY,
+            RasterGeometry::Patches(_)             => gl::PATCHES
+        }
+    }
+
+    /// Gets the primitive mode transform feedback captures the geometry as. Only points, lines
+    /// and triangles can be captured: strips, loops and fans must be unrolled first, since
+    /// `OpenGL ES` 3.0 requires the draw mode to match the capture mode exactly.
+    pub(crate) fn feedback_mode(&self) -> Option<u32> {
+        match self {
+            RasterGeometry::Points    => Some(gl::POINTS),
+            RasterGeometry::Lines     => Some(gl::LINES),
+            RasterGeometry::Triangles => Some(gl::TRIANGLES),
+            _ => None
+        }
+    }
+
+    /// Checks if the geometry can be drawn. Patches need at least one vertex.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            RasterGeometry::Patches(0) => Err("Patches need at least one vertex".to_string()),
+            _ => Ok(())
+        }
+    }
+
+    /// Sets the patch size if the geometry is made of patches. The geometry must be validated
+    /// first.
+    pub(crate) fn bind(&self) {
+        if let RasterGeometry::Patches(n_vertices) = self {
+            unsafe {
+                gl::PatchParameteri(gl::PATCH_VERTICES, *n_vertices as i32);
+            }
+        }
+    }
+}
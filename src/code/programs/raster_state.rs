@@ -0,0 +1,309 @@
+mod blend;
+mod depth;
+mod stencil;
+mod rasterization;
+mod viewport;
+
+pub use blend::*;
+pub use depth::*;
+pub use stencil::*;
+pub use rasterization::*;
+pub use viewport::*;
+
+/// The fixed-function state used by raster calls.
+///
+/// Every raster call applies the whole state, so nothing set by a previous call leaks into the
+/// next one. The default state has blending, depth testing, stencil testing and face culling
+/// disabled, writes every color channel and covers the whole `Framebuffer` without scissoring.
+#[derive(Clone,Debug,PartialEq)]
+pub struct RasterState {
+    blend             : Option<Blend>,
+    attachment_blends : Vec<(usize, Option<Blend>)>,
+    blend_color       : (f32, f32, f32, f32),
+    depth             : Option<Depth>,
+    stencil           : Option<Stencil>,
+    cull_face         : Option<CullFace>,
+    front_face        : FrontFace,
+    polygon_mode      : PolygonMode,
+    polygon_offset    : Option<PolygonOffset>,
+    color_mask        : (bool, bool, bool, bool),
+    alpha_to_coverage : bool,
+    viewports         : Vec<Viewport>,
+    scissors          : Vec<Scissor>
+}
+
+impl Default for RasterState {
+    fn default() -> Self {
+        let blend             = None;
+        let attachment_blends = Vec::new();
+        let blend_color       = (0.0, 0.0, 0.0, 0.0);
+        let depth             = None;
+        let stencil           = None;
+        let cull_face         = None;
+        let front_face        = FrontFace::CounterClockwise;
+        let polygon_mode      = PolygonMode::Fill;
+        let polygon_offset    = None;
+        let color_mask        = (true, true, true, true);
+        let alpha_to_coverage = false;
+        let viewports         = Vec::new();
+        let scissors          = Vec::new();
+        Self {
+            blend, attachment_blends, blend_color, depth, stencil, cull_face, front_face,
+            polygon_mode, polygon_offset, color_mask, alpha_to_coverage, viewports, scissors
+        }
+    }
+}
+
+impl RasterState {
+    /// Creates a new `RasterState` with the default state.
+    pub fn new() -> Self { Default::default() }
+
+    /// Sets the `Blend` of all the color attachments. `None` disables blending.
+    pub fn with_blend(mut self, blend: Option<Blend>) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    /// Overrides the `Blend` of the color attachment at `attachment`. `None` disables blending
+    /// for it.
+    pub fn with_attachment_blend(mut self, attachment: usize, blend: Option<Blend>) -> Self {
+        self.attachment_blends.retain(|(index, _)| *index != attachment);
+        self.attachment_blends.push((attachment, blend));
+        self
+    }
+
+    /// Sets the blend constant color.
+    pub fn with_blend_color(mut self, blend_color: (f32, f32, f32, f32)) -> Self {
+        self.blend_color = blend_color;
+        self
+    }
+
+    /// Sets the `Depth` test. `None` disables depth testing and writing.
+    pub fn with_depth(mut self, depth: Option<Depth>) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets the `Stencil` test. `None` disables stencil testing and writes every stencil bit.
+    pub fn with_stencil(mut self, stencil: Option<Stencil>) -> Self {
+        self.stencil = stencil;
+        self
+    }
+
+    /// Sets the culled faces. `None` disables face culling.
+    pub fn with_cull_face(mut self, cull_face: Option<CullFace>) -> Self {
+        self.cull_face = cull_face;
+        self
+    }
+
+    /// Sets the winding order of front faces.
+    pub fn with_front_face(mut self, front_face: FrontFace) -> Self {
+        self.front_face = front_face;
+        self
+    }
+
+    /// Sets the `PolygonMode`. It's ignored by `OpenGL ES`, which only fills polygons.
+    pub fn with_polygon_mode(mut self, polygon_mode: PolygonMode) -> Self {
+        self.polygon_mode = polygon_mode;
+        self
+    }
+
+    /// Sets the `PolygonOffset`. `None` disables it.
+    pub fn with_polygon_offset(mut self, polygon_offset: Option<PolygonOffset>) -> Self {
+        self.polygon_offset = polygon_offset;
+        self
+    }
+
+    /// Sets which of the `(red, green, blue, alpha)` channels are written.
+    pub fn with_color_mask(mut self, color_mask: (bool, bool, bool, bool)) -> Self {
+        self.color_mask = color_mask;
+        self
+    }
+
+    /// Sets if the fragment alpha is used as the multisample coverage.
+    pub fn with_alpha_to_coverage(mut self, alpha_to_coverage: bool) -> Self {
+        self.alpha_to_coverage = alpha_to_coverage;
+        self
+    }
+
+    /// Sets the `Viewport`. `None` covers the whole `Framebuffer`.
+    pub fn with_viewport(mut self, viewport: Option<Viewport>) -> Self {
+        self.viewports = viewport.into_iter().collect();
+        self
+    }
+
+    /// Sets indexed `Viewport`s, selected in the geometry shader with `gl_ViewportIndex`. Only the
+    /// first one is used where indexed viewports aren't supported.
+    pub fn with_viewports(mut self, viewports: Vec<Viewport>) -> Self {
+        self.viewports = viewports;
+        self
+    }
+
+    /// Sets the `Scissor` rectangle. `None` disables scissoring.
+    pub fn with_scissor(mut self, scissor: Option<Scissor>) -> Self {
+        self.scissors = scissor.into_iter().collect();
+        self
+    }
+
+    /// Sets a `Scissor` rectangle for each indexed `Viewport`. Only the first one is used where
+    /// indexed viewports aren't supported.
+    pub fn with_scissors(mut self, scissors: Vec<Scissor>) -> Self {
+        self.scissors = scissors;
+        self
+    }
+
+    /// Gets the `Blend` of all the color attachments.
+    pub fn blend(&self) -> Option<Blend> { self.blend }
+
+    /// Gets the `Blend` of the color attachment at `attachment`.
+    pub fn attachment_blend(&self, attachment: usize) -> Option<Blend> {
+        self.attachment_blends.iter()
+            .find(|(index, _)| *index == attachment)
+            .map(|(_, blend)| *blend)
+            .unwrap_or(self.blend)
+    }
+
+    /// Gets the blend constant color.
+    pub fn blend_color(&self) -> (f32, f32, f32, f32) { self.blend_color }
+
+    /// Gets the `Depth` test.
+    pub fn depth(&self) -> Option<Depth> { self.depth }
+
+    /// Gets the `Stencil` test.
+    pub fn stencil(&self) -> Option<Stencil> { self.stencil }
+
+    /// Gets the culled faces.
+    pub fn cull_face(&self) -> Option<CullFace> { self.cull_face }
+
+    /// Gets the winding order of front faces.
+    pub fn front_face(&self) -> FrontFace { self.front_face }
+
+    /// Gets the `PolygonMode`.
+    pub fn polygon_mode(&self) -> PolygonMode { self.polygon_mode }
+
+    /// Gets the `PolygonOffset`.
+    pub fn polygon_offset(&self) -> Option<PolygonOffset> { self.polygon_offset }
+
+    /// Gets which of the `(red, green, blue, alpha)` channels are written.
+    pub fn color_mask(&self) -> (bool, bool, bool, bool) { self.color_mask }
+
+    /// Gets if the fragment alpha is used as the multisample coverage.
+    pub fn alpha_to_coverage(&self) -> bool { self.alpha_to_coverage }
+
+    /// Gets the `Viewport`s. Empty means the whole `Framebuffer`.
+    pub fn viewports(&self) -> &[Viewport] { &self.viewports }
+
+    /// Gets the `Scissor` rectangles. Empty means no scissoring.
+    pub fn scissors(&self) -> &[Scissor] { &self.scissors }
+
+    /// Applies the whole state on a `Framebuffer` with `dimensions`.
+    pub(crate) fn bind(&self, dimensions: (usize, usize)) {
+        self.bind_viewports(dimensions);
+        unsafe {
+            // The non-indexed functions affect every attachment, so they reset the overrides
+            // of previous calls.
+            Self::set_enabled(gl::BLEND, self.blend.is_some());
+            if let Some(blend) = self.blend {
+                gl::BlendEquationSeparate(blend.color_equation.get_internal(), blend.alpha_equation.get_internal());
+                gl::BlendFuncSeparate(blend.color_source.get_internal(), blend.color_destination.get_internal(),
+                                      blend.alpha_source.get_internal(), blend.alpha_destination.get_internal());
+            }
+            for (attachment, blend) in &self.attachment_blends {
+                let attachment = *attachment as u32;
+                match blend {
+                    Some(blend) => {
+                        gl::Enablei(gl::BLEND, attachment);
+                        gl::BlendEquationSeparatei(attachment, blend.color_equation.get_internal(), blend.alpha_equation.get_internal());
+                        gl::BlendFuncSeparatei(attachment, blend.color_source.get_internal(), blend.color_destination.get_internal(),
+                                               blend.alpha_source.get_internal(), blend.alpha_destination.get_internal());
+                    },
+                    None => gl::Disablei(gl::BLEND, attachment)
+                }
+            }
+            let (red, green, blue, alpha) = self.blend_color;
+            gl::BlendColor(red, green, blue, alpha);
+
+            let (red, green, blue, alpha) = self.color_mask;
+            gl::ColorMask(red as u8, green as u8, blue as u8, alpha as u8);
+
+            Self::set_enabled(gl::DEPTH_TEST, self.depth.is_some());
+            match self.depth {
+                Some(depth) => {
+                    gl::DepthFunc(depth.function.get_internal());
+                    gl::DepthMask(depth.write as u8);
+                },
+                None => gl::DepthMask(gl::TRUE)
+            }
+
+            Self::set_enabled(gl::STENCIL_TEST, self.stencil.is_some());
+            match self.stencil {
+                Some(stencil) => {
+                    for (face, test) in &[(gl::FRONT, stencil.front), (gl::BACK, stencil.back)] {
+                        gl::StencilFuncSeparate(*face, test.function.get_internal(), test.reference, test.read_mask);
+                        gl::StencilOpSeparate(*face, test.stencil_fail.get_internal(), test.depth_fail.get_internal(), test.pass.get_internal());
+                        gl::StencilMaskSeparate(*face, test.write_mask);
+                    }
+                },
+                None => gl::StencilMask(!0)
+            }
+
+            Self::set_enabled(gl::CULL_FACE, self.cull_face.is_some());
+            if let Some(cull_face) = self.cull_face {
+                gl::CullFace(cull_face.get_internal());
+            }
+            gl::FrontFace(self.front_face.get_internal());
+            // `OpenGL ES` doesn't have `PolygonMode` and always fills polygons.
+            if gl::PolygonMode::is_loaded() {
+                gl::PolygonMode(gl::FRONT_AND_BACK, self.polygon_mode.get_internal());
+            }
+
+            let polygon_offset = self.polygon_offset.is_some();
+            Self::set_enabled(gl::POLYGON_OFFSET_FILL, polygon_offset);
+            Self::set_enabled(gl::POLYGON_OFFSET_LINE, polygon_offset);
+            Self::set_enabled(gl::POLYGON_OFFSET_POINT, polygon_offset);
+            if let Some(polygon_offset) = self.polygon_offset {
+                gl::PolygonOffset(polygon_offset.factor, polygon_offset.units);
+            }
+
+            Self::set_enabled(gl::SAMPLE_ALPHA_TO_COVERAGE, self.alpha_to_coverage);
+        }
+    }
+
+    fn bind_viewports(&self, dimensions: (usize, usize)) {
+        let full      = [Viewport::new((0, 0), dimensions)];
+        let viewports = if self.viewports.is_empty() { &full[..] } else { &self.viewports[..] };
+        let indexed   = gl::ViewportIndexedf::is_loaded() && (viewports.len() > 1 || self.scissors.len() > 1);
+        unsafe {
+            if indexed {
+                // The non-indexed calls reset every slot, so the ones left from a previous call
+                // with more viewports or scissors cover the whole `Framebuffer`.
+                let (width, height) = (dimensions.0 as i32, dimensions.1 as i32);
+                gl::Viewport(0, 0, width, height);
+                gl::DepthRangef(0.0, 1.0);
+                gl::Scissor(0, 0, width, height);
+                for (index, viewport) in viewports.iter().enumerate() {
+                    let index = index as u32;
+                    gl::ViewportIndexedf(index, viewport.x as f32, viewport.y as f32, viewport.width as f32, viewport.height as f32);
+                    gl::DepthRangeIndexed(index, viewport.depth_range.0 as f64, viewport.depth_range.1 as f64);
+                }
+                Self::set_enabled(gl::SCISSOR_TEST, !self.scissors.is_empty());
+                for (index, scissor) in self.scissors.iter().enumerate() {
+                    gl::ScissorIndexed(index as u32, scissor.x as i32, scissor.y as i32, scissor.width as i32, scissor.height as i32);
+                }
+            } else {
+                let viewport = &viewports[0];
+                gl::Viewport(viewport.x as i32, viewport.y as i32, viewport.width as i32, viewport.height as i32);
+                gl::DepthRangef(viewport.depth_range.0, viewport.depth_range.1);
+                Scissor::bind(self.scissors.first());
+            }
+        }
+    }
+
+    unsafe fn set_enabled(capability: u32, enabled: bool) {
+        if enabled {
+            gl::Enable(capability);
+        } else {
+            gl::Disable(capability);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Module for blending.
+
+/// Factors multiplying the source and destination colors before blending.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum BlendFactor {
+    /// `(0, 0, 0, 0)`.
+    Zero,
+    /// `(1, 1, 1, 1)`.
+    One,
+    /// The source color.
+    SourceColor,
+    /// One minus the source color.
+    OneMinusSourceColor,
+    /// The destination color.
+    DestinationColor,
+    /// One minus the destination color.
+    OneMinusDestinationColor,
+    /// The source alpha.
+    SourceAlpha,
+    /// One minus the source alpha.
+    OneMinusSourceAlpha,
+    /// The destination alpha.
+    DestinationAlpha,
+    /// One minus the destination alpha.
+    OneMinusDestinationAlpha,
+    /// The blend constant color.
+    ConstantColor,
+    /// One minus the blend constant color.
+    OneMinusConstantColor,
+    /// The blend constant alpha.
+    ConstantAlpha,
+    /// One minus the blend constant alpha.
+    OneMinusConstantAlpha,
+    /// The source alpha saturated by one minus the destination alpha.
+    SourceAlphaSaturate
+}
+
+impl BlendFactor {
+    pub(crate) fn get_internal(&self) -> u32 {
+        match self {
+            Self::Zero                     => gl::ZERO,
+            Self::One                      => gl::ONE,
+            Self::SourceColor              => gl::SRC_COLOR,
+            Self::OneMinusSourceColor      => gl::ONE_MINUS_SRC_COLOR,
+            Self::DestinationColor         => gl::DST_COLOR,
+            Self::OneMinusDestinationColor => gl::ONE_MINUS_DST_COLOR,
+            Self::SourceAlpha              => gl::SRC_ALPHA,
+            Self::OneMinusSourceAlpha      => gl::ONE_MINUS_SRC_ALPHA,
+            Self::DestinationAlpha         => gl::DST_ALPHA,
+            Self::OneMinusDestinationAlpha => gl::ONE_MINUS_DST_ALPHA,
+            Self::ConstantColor            => gl::CONSTANT_COLOR,
+            Self::OneMinusConstantColor    => gl::ONE_MINUS_CONSTANT_COLOR,
+            Self::ConstantAlpha            => gl::CONSTANT_ALPHA,
+            Self::OneMinusConstantAlpha    => gl::ONE_MINUS_CONSTANT_ALPHA,
+            Self::SourceAlphaSaturate      => gl::SRC_ALPHA_SATURATE
+        }
+    }
+}
+
+/// Equations combining the weighted source and destination colors.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum BlendEquation {
+    /// `source + destination`.
+    Add,
+    /// `source - destination`.
+    Subtract,
+    /// `destination - source`.
+    ReverseSubtract,
+    /// `min(source, destination)`. The factors are ignored.
+    Min,
+    /// `max(source, destination)`. The factors are ignored.
+    Max
+}
+
+impl BlendEquation {
+    pub(crate) fn get_internal(&self) -> u32 {
+        match self {
+            Self::Add             => gl::FUNC_ADD,
+            Self::Subtract        => gl::FUNC_SUBTRACT,
+            Self::ReverseSubtract => gl::FUNC_REVERSE_SUBTRACT,
+            Self::Min             => gl::MIN,
+            Self::Max             => gl::MAX
+        }
+    }
+}
+
+/// Blending of the color and alpha channels.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Blend {
+    /// Equation for the color channels.
+    pub color_equation           : BlendEquation,
+    /// Source factor for the color channels.
+    pub color_source             : BlendFactor,
+    /// Destination factor for the color channels.
+    pub color_destination        : BlendFactor,
+    /// Equation for the alpha channel.
+    pub alpha_equation           : BlendEquation,
+    /// Source factor for the alpha channel.
+    pub alpha_source             : BlendFactor,
+    /// Destination factor for the alpha channel.
+    pub alpha_destination        : BlendFactor
+}
+
+impl Blend {
+    /// Creates a `Blend` with the same `equation` and factors for the color and alpha channels.
+    pub fn new(equation: BlendEquation, source: BlendFactor, destination: BlendFactor) -> Self {
+        Self::separate((equation, source, destination), (equation, source, destination))
+    }
+
+    /// Creates a `Blend` with separate `(equation, source, destination)` for the color and alpha
+    /// channels.
+    pub fn separate(color: (BlendEquation, BlendFactor, BlendFactor), alpha: (BlendEquation, BlendFactor, BlendFactor)) -> Self {
+        let (color_equation, color_source, color_destination) = color;
+        let (alpha_equation, alpha_source, alpha_destination) = alpha;
+        Self { color_equation, color_source, color_destination, alpha_equation, alpha_source, alpha_destination }
+    }
+
+    /// Common alpha blending: `source * source_alpha + destination * (1 - source_alpha)`.
+    pub fn alpha() -> Self {
+        Self::new(BlendEquation::Add, BlendFactor::SourceAlpha, BlendFactor::OneMinusSourceAlpha)
+    }
+
+    /// Additive blending: `source + destination`.
+    pub fn additive() -> Self {
+        Self::new(BlendEquation::Add, BlendFactor::One, BlendFactor::One)
+    }
+}
@@ -0,0 +1,59 @@
+//! Module for depth testing.
+
+/// Functions comparing a fragment value against a reference value.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum CompareFunction {
+    /// Never passes.
+    Never,
+    /// Passes if the value is less than the reference.
+    Less,
+    /// Passes if the value is equal to the reference.
+    Equal,
+    /// Passes if the value is less than or equal to the reference.
+    LessOrEqual,
+    /// Passes if the value is greater than the reference.
+    Greater,
+    /// Passes if the value isn't equal to the reference.
+    NotEqual,
+    /// Passes if the value is greater than or equal to the reference.
+    GreaterOrEqual,
+    /// Always passes.
+    Always
+}
+
+impl CompareFunction {
+    pub(crate) fn get_internal(&self) -> u32 {
+        match self {
+            Self::Never          => gl::NEVER,
+            Self::Less           => gl::LESS,
+            Self::Equal          => gl::EQUAL,
+            Self::LessOrEqual    => gl::LEQUAL,
+            Self::Greater        => gl::GREATER,
+            Self::NotEqual       => gl::NOTEQUAL,
+            Self::GreaterOrEqual => gl::GEQUAL,
+            Self::Always         => gl::ALWAYS
+        }
+    }
+}
+
+/// Depth testing.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Depth {
+    /// Function comparing the fragment depth against the stored depth.
+    pub function : CompareFunction,
+    /// Whether the fragments passing the test write their depth.
+    pub write    : bool
+}
+
+impl Depth {
+    /// Creates a new `Depth` test.
+    pub fn new(function: CompareFunction, write: bool) -> Self {
+        Self { function, write }
+    }
+}
+
+impl Default for Depth {
+    fn default() -> Self {
+        Self::new(CompareFunction::Less, true)
+    }
+}
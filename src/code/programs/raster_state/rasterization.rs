@@ -0,0 +1,77 @@
+//! Module for primitive rasterization.
+
+/// Faces that can be culled.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum CullFace {
+    /// Culls front faces.
+    Front,
+    /// Culls back faces.
+    Back,
+    /// Culls all the faces. Only points and lines are rasterized.
+    FrontAndBack
+}
+
+impl CullFace {
+    pub(crate) fn get_internal(&self) -> u32 {
+        match self {
+            Self::Front        => gl::FRONT,
+            Self::Back         => gl::BACK,
+            Self::FrontAndBack => gl::FRONT_AND_BACK
+        }
+    }
+}
+
+/// Winding order of front faces.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum FrontFace {
+    /// Counter-clockwise triangles are front faces.
+    CounterClockwise,
+    /// Clockwise triangles are front faces.
+    Clockwise
+}
+
+impl FrontFace {
+    pub(crate) fn get_internal(&self) -> u32 {
+        match self {
+            Self::CounterClockwise => gl::CCW,
+            Self::Clockwise        => gl::CW
+        }
+    }
+}
+
+/// How polygons are rasterized.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum PolygonMode {
+    /// Fills the polygons.
+    Fill,
+    /// Rasterizes the polygons' edges as lines.
+    Line,
+    /// Rasterizes the polygons' vertices as points.
+    Point
+}
+
+impl PolygonMode {
+    pub(crate) fn get_internal(&self) -> u32 {
+        match self {
+            Self::Fill  => gl::FILL,
+            Self::Line  => gl::LINE,
+            Self::Point => gl::POINT
+        }
+    }
+}
+
+/// Offset added to the depth of the rasterized polygons.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct PolygonOffset {
+    /// Factor multiplying the polygon's depth slope.
+    pub factor : f32,
+    /// Units of the minimum resolvable depth difference.
+    pub units  : f32
+}
+
+impl PolygonOffset {
+    /// Creates a new `PolygonOffset`.
+    pub fn new(factor: f32, units: f32) -> Self {
+        Self { factor, units }
+    }
+}
@@ -0,0 +1,107 @@
+//! Module for stencil testing.
+
+use super::CompareFunction;
+
+/// Operations on the stored stencil value.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum StencilOperation {
+    /// Keeps the stored value.
+    Keep,
+    /// Sets the stored value to `0`.
+    Zero,
+    /// Replaces the stored value by the reference.
+    Replace,
+    /// Increments the stored value, clamping it to the maximum.
+    Increment,
+    /// Increments the stored value, wrapping it to `0`.
+    IncrementWrap,
+    /// Decrements the stored value, clamping it to `0`.
+    Decrement,
+    /// Decrements the stored value, wrapping it to the maximum.
+    DecrementWrap,
+    /// Inverts the bits of the stored value.
+    Invert
+}
+
+impl StencilOperation {
+    pub(crate) fn get_internal(&self) -> u32 {
+        match self {
+            Self::Keep          => gl::KEEP,
+            Self::Zero          => gl::ZERO,
+            Self::Replace       => gl::REPLACE,
+            Self::Increment     => gl::INCR,
+            Self::IncrementWrap => gl::INCR_WRAP,
+            Self::Decrement     => gl::DECR,
+            Self::DecrementWrap => gl::DECR_WRAP,
+            Self::Invert        => gl::INVERT
+        }
+    }
+}
+
+/// Stencil testing of a single face.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct StencilFace {
+    /// Function comparing the masked reference against the masked stored value.
+    pub function     : CompareFunction,
+    /// Reference value.
+    pub reference    : i32,
+    /// Mask applied to the reference and to the stored value before comparing them.
+    pub read_mask    : u32,
+    /// Mask of the bits written to the stored value.
+    pub write_mask   : u32,
+    /// Operation when the stencil test fails.
+    pub stencil_fail : StencilOperation,
+    /// Operation when the stencil test passes but the depth test fails.
+    pub depth_fail   : StencilOperation,
+    /// Operation when both tests pass.
+    pub pass         : StencilOperation
+}
+
+impl StencilFace {
+    /// Creates a `StencilFace` comparing against `reference` with all the bits and keeping the
+    /// stored value.
+    pub fn new(function: CompareFunction, reference: i32) -> Self {
+        let read_mask    = !0;
+        let write_mask   = !0;
+        let stencil_fail = StencilOperation::Keep;
+        let depth_fail   = StencilOperation::Keep;
+        let pass         = StencilOperation::Keep;
+        Self { function, reference, read_mask, write_mask, stencil_fail, depth_fail, pass }
+    }
+
+    /// Sets the `(stencil_fail, depth_fail, pass)` operations.
+    pub fn with_operations(mut self, stencil_fail: StencilOperation, depth_fail: StencilOperation, pass: StencilOperation) -> Self {
+        self.stencil_fail = stencil_fail;
+        self.depth_fail   = depth_fail;
+        self.pass         = pass;
+        self
+    }
+
+    /// Sets the read and write masks.
+    pub fn with_masks(mut self, read_mask: u32, write_mask: u32) -> Self {
+        self.read_mask  = read_mask;
+        self.write_mask = write_mask;
+        self
+    }
+}
+
+/// Stencil testing of front and back faces.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Stencil {
+    /// Test of the front faces.
+    pub front : StencilFace,
+    /// Test of the back faces.
+    pub back  : StencilFace
+}
+
+impl Stencil {
+    /// Creates a `Stencil` with separate `front` and `back` tests.
+    pub fn new(front: StencilFace, back: StencilFace) -> Self {
+        Self { front, back }
+    }
+
+    /// Creates a `Stencil` with the same test for both faces.
+    pub fn all(face: StencilFace) -> Self {
+        Self::new(face, face)
+    }
+}
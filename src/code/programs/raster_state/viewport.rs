@@ -0,0 +1,66 @@
+//! Module for viewports and scissor rectangles.
+
+/// A rectangle of the `Framebuffer` where the normalized device coordinates are mapped to.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Viewport {
+    /// Left position in pixels.
+    pub x           : usize,
+    /// Bottom position in pixels.
+    pub y           : usize,
+    /// Width in pixels.
+    pub width       : usize,
+    /// Height in pixels.
+    pub height      : usize,
+    /// The `(near, far)` window depths the normalized device depths are mapped to.
+    pub depth_range : (f32, f32)
+}
+
+impl Viewport {
+    /// Creates a new `Viewport` at `(x, y)` with `(width, height)` and the full depth range.
+    pub fn new(position: (usize, usize), dimensions: (usize, usize)) -> Self {
+        let (x, y)          = position;
+        let (width, height) = dimensions;
+        let depth_range     = (0.0, 1.0);
+        Self { x, y, width, height, depth_range }
+    }
+
+    /// Sets the `(near, far)` depth range.
+    pub fn with_depth_range(mut self, depth_range: (f32, f32)) -> Self {
+        self.depth_range = depth_range;
+        self
+    }
+}
+
+/// A rectangle of the `Framebuffer` outside of which fragments are discarded.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct Scissor {
+    /// Left position in pixels.
+    pub x      : usize,
+    /// Bottom position in pixels.
+    pub y      : usize,
+    /// Width in pixels.
+    pub width  : usize,
+    /// Height in pixels.
+    pub height : usize
+}
+
+impl Scissor {
+    /// Creates a new `Scissor` at `(x, y)` with `(width, height)`.
+    pub fn new(position: (usize, usize), dimensions: (usize, usize)) -> Self {
+        let (x, y)          = position;
+        let (width, height) = dimensions;
+        Self { x, y, width, height }
+    }
+
+    pub(crate) fn bind(scissor: Option<&Scissor>) {
+        unsafe {
+            match scissor {
+                Some(scissor) => {
+                    gl::Enable(gl::SCISSOR_TEST);
+                    gl::Scissor(scissor.x as i32, scissor.y as i32, scissor.width as i32, scissor.height as i32);
+                },
+                None => gl::Disable(gl::SCISSOR_TEST)
+            }
+        }
+    }
+}
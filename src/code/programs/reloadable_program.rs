@@ -0,0 +1,244 @@
+use crate::{Context, Program, ProgramBuilder, RasterProgram, ComputeProgram, Shader, ShaderStage};
+use crate::{DirectorySource, Preprocessor, Uniform};
+use std::ops::DerefMut;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A program type that a `ReloadableProgram` can rebuild.
+pub trait Reloadable: DerefMut<Target = Program> + Sized {
+    /// Links the shaders attached to the `ProgramBuilder`.
+    fn build(context: &Context, builder: &ProgramBuilder) -> Result<Self, String>;
+}
+
+impl Reloadable for RasterProgram {
+    fn build(context: &Context, builder: &ProgramBuilder) -> Result<Self, String> {
+        builder.build_raster(context)
+    }
+}
+
+impl Reloadable for ComputeProgram {
+    fn build(context: &Context, builder: &ProgramBuilder) -> Result<Self, String> {
+        builder.build_compute(context)
+    }
+}
+
+/// A `RasterProgram` or `ComputeProgram` built from shader files, which is rebuilt when the files
+/// or the files they include change. A failed rebuild keeps the previous program.
+pub struct ReloadableProgram<P: Reloadable> {
+    program    : P,
+    directory  : PathBuf,
+    stages     : Vec<(ShaderStage, String)>,
+    defines    : Vec<(String, String)>,
+    files      : Vec<(PathBuf, Option<SystemTime>)>,
+    uniforms   : Vec<(String, Box<dyn Uniform>)>,
+    last_error : Option<String>,
+    #[cfg(feature = "notify")]
+    watcher    : Option<watcher::Watcher>
+}
+
+impl<P: Reloadable> ReloadableProgram<P> {
+    /// Builds a program from the shader file of each stage, with paths relative to `directory`.
+    /// The `defines` are injected in every stage.
+    pub fn new(context: &Context, directory: impl Into<PathBuf>, stages: &[(ShaderStage, &str)], defines: &[(&str, &str)]) -> Result<Self, String> {
+        let directory  = directory.into();
+        let stages : Vec<_> = stages.iter().map(|(stage, path)| (*stage, path.to_string())).collect();
+        let defines : Vec<_> = defines.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect();
+        let (program, files) = Self::build(context, &directory, &stages, &defines)?;
+        let files      = Self::timestamps(files);
+        let uniforms   = Vec::new();
+        let last_error = None;
+        #[cfg(feature = "notify")]
+        let watcher    = Self::watcher(&files);
+        Ok(Self {
+            program, directory, stages, defines, files, uniforms, last_error,
+            #[cfg(feature = "notify")]
+            watcher
+        })
+    }
+
+    /// Gets the current program.
+    pub fn program(&self) -> &P { &self.program }
+
+    /// Gets the files the program was built from, including the included ones.
+    pub fn files(&self) -> Vec<&Path> {
+        self.files.iter().map(|(path, _)| path.as_path()).collect()
+    }
+
+    /// Gets the error of the last failed rebuild, if the last rebuild failed.
+    pub fn last_error(&self) -> Option<&str> { self.last_error.as_deref() }
+
+    /// Sets `value` to the uniform called `name` now and after every successful rebuild.
+    pub fn set<U: Uniform + 'static>(&mut self, name: &str, value: U) -> Result<(), String> {
+        self.program.set(name, &value)?;
+        self.uniforms.retain(|(uniform, _)| uniform != name);
+        self.uniforms.push((name.to_string(), Box::new(value)));
+        Ok(())
+    }
+
+    /// Checks if any file changed since the last build.
+    pub fn has_changed(&self) -> bool {
+        #[cfg(feature = "notify")]
+        {
+            if let Some(watcher) = &self.watcher {
+                return watcher.has_changed()
+            }
+        }
+        self.files.iter().any(|(path, modified)| Self::modified(path) != *modified)
+    }
+
+    /// Rebuilds the program if any file changed. Returns `Ok(true)` if it was rebuilt, and the
+    /// error if the rebuild failed, in which case the previous program is kept until the files
+    /// change again.
+    pub fn reload_if_changed(&mut self, context: &Context) -> Result<bool, String> {
+        if !self.has_changed() {
+            return Ok(false)
+        }
+        self.reload(context).map(|_| true)
+    }
+
+    /// Rebuilds the program unconditionally, keeping the previous one if it fails.
+    pub fn reload(&mut self, context: &Context) -> Result<(), String> {
+        let files = self.files.iter().map(|(path, _)| path.clone()).collect();
+        self.files = Self::timestamps(files);
+        match Self::build(context, &self.directory, &self.stages, &self.defines) {
+            Ok((mut program, files)) => {
+                // Uniforms removed from the new sources are ignored.
+                let lenient = self.program.is_lenient();
+                program.set_lenient(true);
+                for (name, value) in self.uniforms.iter() {
+                    if let Err(error) = program.set(name, &**value) {
+                        return Err(self.fail(error))
+                    }
+                }
+                program.set_lenient(lenient);
+                self.program    = program;
+                self.files      = Self::timestamps(files);
+                self.last_error = None;
+                #[cfg(feature = "notify")]
+                {
+                    self.watcher = Self::watcher(&self.files);
+                }
+                Ok(())
+            },
+            Err(error) => Err(self.fail(error))
+        }
+    }
+
+    fn fail(&mut self, error: String) -> String {
+        #[cfg(feature = "notify")]
+        {
+            if let Some(watcher) = &self.watcher {
+                watcher.reset();
+            }
+        }
+        self.last_error = Some(error.clone());
+        error
+    }
+
+    fn build(context: &Context, directory: &Path, stages: &[(ShaderStage, String)], defines: &[(String, String)]) -> Result<(P, Vec<PathBuf>), String> {
+        let provider     = DirectorySource::new(directory);
+        let preprocessor = defines.iter().fold(Preprocessor::new(&provider), |preprocessor, (name, value)| preprocessor.with_define(name, value));
+        let sources      = stages.iter()
+            .map(|(stage, path)| preprocessor.process(path).map(|source| (*stage, source)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut files : Vec<PathBuf> = Vec::new();
+        for file in sources.iter().flat_map(|(_, source)| source.files().iter()) {
+            let file = directory.join(file);
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+        let shaders = sources.iter()
+            .map(|(stage, source)| Shader::new(context, stage.internal(), source))
+            .collect::<Result<Vec<_>, _>>()?;
+        let builder = shaders.iter().fold(ProgramBuilder::new(), |builder, shader| builder.with_shader(shader));
+        let program = P::build(context, &builder)?;
+        Ok((program, files))
+    }
+
+    fn timestamps(files: Vec<PathBuf>) -> Vec<(PathBuf, Option<SystemTime>)> {
+        files.into_iter().map(|path| {
+            let modified = Self::modified(&path);
+            (path, modified)
+        }).collect()
+    }
+
+    fn modified(path: &Path) -> Option<SystemTime> {
+        std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    /// Watches the `files`, or returns `None` to fall back to polling if they can't be watched.
+    #[cfg(feature = "notify")]
+    fn watcher(files: &[(PathBuf, Option<SystemTime>)]) -> Option<watcher::Watcher> {
+        let files : Vec<&Path> = files.iter().map(|(path, _)| path.as_path()).collect();
+        watcher::Watcher::new(&files).ok()
+    }
+}
+
+#[cfg(feature = "notify")]
+mod watcher {
+    use notify::{RecommendedWatcher, RecursiveMode, Watcher as _};
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Flags changes of the watched files as they happen. The directories containing the files are
+    /// watched instead of the files themselves, so files replaced by editors that save to a new
+    /// file and rename it are still watched.
+    pub(super) struct Watcher {
+        _watcher : RecommendedWatcher,
+        changed  : Arc<AtomicBool>
+    }
+
+    impl Watcher {
+        pub(super) fn new(files: &[&Path]) -> notify::Result<Self> {
+            let changed = Arc::new(AtomicBool::new(false));
+            let flag = changed.clone();
+            let watched : Vec<PathBuf> = files.iter().map(|file| normalize(file)).collect();
+            let mut directories : Vec<PathBuf> = Vec::new();
+            for file in watched.iter() {
+                if let Some(directory) = file.parent() {
+                    if !directories.iter().any(|watched| watched == directory) {
+                        directories.push(directory.to_path_buf());
+                    }
+                }
+            }
+            let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let changed = match event {
+                    Ok(event) => {
+                        (event.kind.is_modify() || event.kind.is_create() || event.kind.is_remove())
+                            && event.paths.iter().any(|path| watched.contains(&normalize(path)))
+                    },
+                    Err(_) => true
+                };
+                if changed {
+                    flag.store(true, Ordering::SeqCst);
+                }
+            })?;
+            for directory in directories {
+                watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+            }
+            Ok(Self { _watcher: watcher, changed })
+        }
+
+        pub(super) fn has_changed(&self) -> bool {
+            self.changed.load(Ordering::SeqCst)
+        }
+
+        pub(super) fn reset(&self) {
+            self.changed.store(false, Ordering::SeqCst);
+        }
+    }
+
+    /// Canonicalizes the directory of `path`, which exists even if the file was removed, so the
+    /// paths reported by the watcher can be compared with the watched ones.
+    fn normalize(path: &Path) -> PathBuf {
+        match (path.parent(), path.file_name()) {
+            (Some(directory), Some(name)) => {
+                let directory = if directory.as_os_str().is_empty() { Path::new(".") } else { directory };
+                directory.canonicalize().map(|directory| directory.join(name)).unwrap_or_else(|_| path.to_path_buf())
+            },
+            _ => path.to_path_buf()
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::ImageFormat;
+
+/// How a shader accesses a storage image.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum ImageAccess {
+    /// Only `imageLoad`.
+    ReadOnly,
+    /// Only `imageStore`.
+    WriteOnly,
+    /// `imageLoad`, `imageStore` and atomics.
+    ReadWrite
+}
+
+impl ImageAccess {
+    /// Gets `OpenGL` internal enumeration.
+    pub fn internal(&self) -> u32 {
+        match self {
+            ImageAccess::ReadOnly  => gl::READ_ONLY,
+            ImageAccess::WriteOnly => gl::WRITE_ONLY,
+            ImageAccess::ReadWrite => gl::READ_WRITE
+        }
+    }
+}
+
+/// Describes which part of an `Image` is bound as a storage image and how it's accessed.
+#[derive(Clone,Copy)]
+pub struct StorageImageBinding {
+    access : ImageAccess,
+    level  : usize,
+    layer  : Option<usize>,
+    format : Option<ImageFormat>
+}
+
+impl Default for StorageImageBinding {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StorageImageBinding {
+    /// Creates a read-write binding of all the layers of the mip level 0 with the image's own
+    /// format.
+    pub fn new() -> Self {
+        let access = ImageAccess::ReadWrite;
+        let level  = 0;
+        let layer  = None;
+        let format = None;
+        Self { access, level, layer, format }
+    }
+
+    /// Sets the `ImageAccess`.
+    pub fn with_access(mut self, access: ImageAccess) -> Self {
+        self.access = access;
+        self
+    }
+
+    /// Sets the mip level.
+    pub fn with_level(mut self, level: usize) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Binds a single layer of an array, cube map or 3D image, or all of them if `None`. A single
+    /// layer is accessed in the shader as a 2D image.
+    pub fn with_layer(mut self, layer: Option<usize>) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Interprets the texels with another `ImageFormat` of the same size, or with the image's own
+    /// format if `None`.
+    pub fn with_format(mut self, format: Option<ImageFormat>) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Gets the `ImageAccess`.
+    pub fn access(&self) -> ImageAccess { self.access }
+
+    /// Gets the mip level.
+    pub fn level(&self) -> usize { self.level }
+
+    /// Gets the bound layer, or `None` if all layers are bound.
+    pub fn layer(&self) -> Option<usize> { self.layer }
+
+    /// Gets the format override.
+    pub fn format(&self) -> Option<ImageFormat> { self.format }
+}
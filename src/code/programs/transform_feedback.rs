@@ -0,0 +1,89 @@
+use crate::{Buffer, BufferRange, Context, GLContext};
+
+type TransformFeedbackResource = u32;
+
+/// The output `Buffer` bindings of transform feedback and a query for the number of primitives
+/// written by the last `Raster::capture`.
+pub struct TransformFeedback {
+    _gl      : GLContext,
+    resource : TransformFeedbackResource,
+    query    : u32
+}
+
+impl TransformFeedback {
+    /// Creates a new `TransformFeedback` without buffers.
+    pub fn new(context: &Context) -> Self {
+        let gl = context.gl_context();
+        let (resource, query) = unsafe {
+            let mut resource = 0;
+            gl::GenTransformFeedbacks(1, &mut resource);
+            let mut query = 0;
+            gl::GenQueries(1, &mut query);
+            (resource, query)
+        };
+        Self { _gl: gl, resource, query }
+    }
+
+    /// Gets the `TransformFeedbackResource`.
+    pub fn resource(&self) -> TransformFeedbackResource { self.resource }
+
+    /// Binds a `Buffer` to capture the varyings at `index`. Interleaved varyings are all written
+    /// to index `0`, separate varyings are written to the index of their declaration.
+    pub fn bind_buffer(&self, index: usize, buffer: &Buffer) -> Result<(), String> {
+        self.bind_buffer_range(index, buffer, BufferRange::new(0, buffer.size()))
+    }
+
+    /// Binds a `BufferRange` of a `Buffer` to capture the varyings at `index`. It fails if the
+    /// range is empty.
+    pub fn bind_buffer_range(&self, index: usize, buffer: &Buffer, range: BufferRange) -> Result<(), String> {
+        if range.size == 0 {
+            return Err("Transform feedback can't capture into an empty buffer range".to_string());
+        }
+        unsafe {
+            gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, self.resource());
+            gl::BindBufferRange(gl::TRANSFORM_FEEDBACK_BUFFER, index as u32, buffer.resource(), range.offset as isize, range.size as isize);
+            gl::BindBuffer(gl::TRANSFORM_FEEDBACK_BUFFER, 0);
+            gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, 0);
+        }
+        Ok(())
+    }
+
+    /// Gets the number of primitives written by the last `Raster::capture`, waiting for the
+    /// capture to finish. Primitives that didn't fit in the buffers aren't counted.
+    pub fn primitives_written(&self) -> usize {
+        let mut primitives = 0;
+        unsafe {
+            gl::GetQueryObjectuiv(self.query, gl::QUERY_RESULT, &mut primitives);
+        }
+        primitives as usize
+    }
+
+    /// Starts capturing the primitives of the current program in the `primitive_mode`.
+    pub(crate) fn begin(&self, primitive_mode: u32) {
+        unsafe {
+            gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, self.resource());
+            gl::BeginQuery(gl::TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN, self.query);
+            gl::Enable(gl::RASTERIZER_DISCARD);
+            gl::BeginTransformFeedback(primitive_mode);
+        }
+    }
+
+    /// Stops capturing and restores rasterization.
+    pub(crate) fn end(&self) {
+        unsafe {
+            gl::EndTransformFeedback();
+            gl::Disable(gl::RASTERIZER_DISCARD);
+            gl::EndQuery(gl::TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN);
+            gl::BindTransformFeedback(gl::TRANSFORM_FEEDBACK, 0);
+        }
+    }
+}
+
+impl Drop for TransformFeedback {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteQueries(1, &self.query);
+            gl::DeleteTransformFeedbacks(1, &self.resource);
+        }
+    }
+}
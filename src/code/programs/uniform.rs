@@ -0,0 +1,164 @@
+use crate::UniformType;
+
+/// A value that can be set to a uniform of a `Program`.
+pub trait Uniform {
+    /// Gets the `UniformType` of the uniform this value can be set to. Arrays report the type of
+    /// their elements.
+    fn uniform_type(&self) -> UniformType;
+
+    /// Gets the number of elements if the value is an array, or `None` if it's a single value.
+    fn array_len(&self) -> Option<usize> { None }
+
+    /// Sets the value to the uniform at `location` of the program in use.
+    fn set_uniform(&self, location: usize);
+}
+
+/// A `Uniform` that can be an element of a uniform array. Arrays and slices of `UniformElement`s
+/// are set to consecutive locations with a single call.
+pub trait UniformElement: Uniform + Sized {
+    /// Gets the `UniformType` of the uniform this element can be set to.
+    fn element_type() -> UniformType;
+
+    /// Sets the `elements` to the uniform array starting at `location` of the program in use.
+    fn set_uniform_array(elements: &[Self], location: usize);
+}
+
+macro_rules! impl_uniform {
+    ($($type_:ty => $uniform_type:ident, $scalar:ty, |$value:ident| $components:expr, |$location:ident, $count:ident, $data:ident| $set:expr);* $(;)?) => {
+        $(
+            impl Uniform for $type_ {
+                fn uniform_type(&self) -> UniformType { UniformType::$uniform_type }
+
+                fn set_uniform(&self, location: usize) {
+                    Self::set_uniform_array(std::slice::from_ref(self), location)
+                }
+            }
+
+            impl UniformElement for $type_ {
+                fn element_type() -> UniformType { UniformType::$uniform_type }
+
+                #[allow(trivial_casts, trivial_numeric_casts)]
+                fn set_uniform_array(elements: &[Self], location: usize) {
+                    let components : Vec<_> = elements.iter().map(|$value| $components).collect();
+                    let $location = location as i32;
+                    let $count = elements.len() as i32;
+                    let $data = components.as_ptr() as *const $scalar;
+                    unsafe {
+                        $set;
+                    }
+                }
+            }
+        )*
+    }
+}
+
+/// Implements `Uniform` and `UniformElement` for a type by converting it to one of the types
+/// supported out of the box.
+#[allow(unused_macros)]
+macro_rules! impl_uniform_via {
+    ($($type_:ty => $via:ty, |$value:ident| $convert:expr);* $(;)?) => {
+        $(
+            impl crate::Uniform for $type_ {
+                fn uniform_type(&self) -> crate::UniformType {
+                    <$via as crate::UniformElement>::element_type()
+                }
+
+                fn set_uniform(&self, location: usize) {
+                    let $value = self;
+                    let value : $via = $convert;
+                    crate::Uniform::set_uniform(&value, location);
+                }
+            }
+
+            impl crate::UniformElement for $type_ {
+                fn element_type() -> crate::UniformType {
+                    <$via as crate::UniformElement>::element_type()
+                }
+
+                fn set_uniform_array(elements: &[Self], location: usize) {
+                    let values : Vec<$via> = elements.iter().map(|$value| $convert).collect();
+                    <$via as crate::UniformElement>::set_uniform_array(&values, location);
+                }
+            }
+        )*
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "mint")]
+mod mint;
+
+impl_uniform!(
+    bool                     => Bool,    i32, |v| *v as i32,                                        |l, n, d| gl::Uniform1iv(l, n, d);
+    (bool, bool)             => BVec2,   i32, |v| [v.0 as i32, v.1 as i32],                         |l, n, d| gl::Uniform2iv(l, n, d);
+    (bool, bool, bool)       => BVec3,   i32, |v| [v.0 as i32, v.1 as i32, v.2 as i32],             |l, n, d| gl::Uniform3iv(l, n, d);
+    (bool, bool, bool, bool) => BVec4,   i32, |v| [v.0 as i32, v.1 as i32, v.2 as i32, v.3 as i32], |l, n, d| gl::Uniform4iv(l, n, d);
+    f32                      => Float,   f32, |v| *v,                                               |l, n, d| gl::Uniform1fv(l, n, d);
+    (f32, f32)               => Vec2,    f32, |v| [v.0, v.1],                                       |l, n, d| gl::Uniform2fv(l, n, d);
+    (f32, f32, f32)          => Vec3,    f32, |v| [v.0, v.1, v.2],                                  |l, n, d| gl::Uniform3fv(l, n, d);
+    (f32, f32, f32, f32)     => Vec4,    f32, |v| [v.0, v.1, v.2, v.3],                             |l, n, d| gl::Uniform4fv(l, n, d);
+    f64                      => Double,  f64, |v| *v,                                               |l, n, d| gl::Uniform1dv(l, n, d);
+    (f64, f64)               => DVec2,   f64, |v| [v.0, v.1],                                       |l, n, d| gl::Uniform2dv(l, n, d);
+    (f64, f64, f64)          => DVec3,   f64, |v| [v.0, v.1, v.2],                                  |l, n, d| gl::Uniform3dv(l, n, d);
+    (f64, f64, f64, f64)     => DVec4,   f64, |v| [v.0, v.1, v.2, v.3],                             |l, n, d| gl::Uniform4dv(l, n, d);
+    i32                      => Int,     i32, |v| *v,                                               |l, n, d| gl::Uniform1iv(l, n, d);
+    (i32, i32)               => IVec2,   i32, |v| [v.0, v.1],                                       |l, n, d| gl::Uniform2iv(l, n, d);
+    (i32, i32, i32)          => IVec3,   i32, |v| [v.0, v.1, v.2],                                  |l, n, d| gl::Uniform3iv(l, n, d);
+    (i32, i32, i32, i32)     => IVec4,   i32, |v| [v.0, v.1, v.2, v.3],                             |l, n, d| gl::Uniform4iv(l, n, d);
+    u32                      => UInt,    u32, |v| *v,                                               |l, n, d| gl::Uniform1uiv(l, n, d);
+    (u32, u32)               => UVec2,   u32, |v| [v.0, v.1],                                       |l, n, d| gl::Uniform2uiv(l, n, d);
+    (u32, u32, u32)          => UVec3,   u32, |v| [v.0, v.1, v.2],                                  |l, n, d| gl::Uniform3uiv(l, n, d);
+    (u32, u32, u32, u32)     => UVec4,   u32, |v| [v.0, v.1, v.2, v.3],                             |l, n, d| gl::Uniform4uiv(l, n, d);
+    // Matrices are arrays of columns, so `[[f32; 3]; 2]` is a `mat2x3`.
+    [[f32; 2]; 2]            => Mat2,    f32, |v| *v,                                               |l, n, d| gl::UniformMatrix2fv(l, n, gl::FALSE, d);
+    [[f32; 3]; 3]            => Mat3,    f32, |v| *v,                                               |l, n, d| gl::UniformMatrix3fv(l, n, gl::FALSE, d);
+    [[f32; 4]; 4]            => Mat4,    f32, |v| *v,                                               |l, n, d| gl::UniformMatrix4fv(l, n, gl::FALSE, d);
+    [[f32; 3]; 2]            => Mat2x3,  f32, |v| *v,                                               |l, n, d| gl::UniformMatrix2x3fv(l, n, gl::FALSE, d);
+    [[f32; 4]; 2]            => Mat2x4,  f32, |v| *v,                                               |l, n, d| gl::UniformMatrix2x4fv(l, n, gl::FALSE, d);
+    [[f32; 2]; 3]            => Mat3x2,  f32, |v| *v,                                               |l, n, d| gl::UniformMatrix3x2fv(l, n, gl::FALSE, d);
+    [[f32; 4]; 3]            => Mat3x4,  f32, |v| *v,                                               |l, n, d| gl::UniformMatrix3x4fv(l, n, gl::FALSE, d);
+    [[f32; 2]; 4]            => Mat4x2,  f32, |v| *v,                                               |l, n, d| gl::UniformMatrix4x2fv(l, n, gl::FALSE, d);
+    [[f32; 3]; 4]            => Mat4x3,  f32, |v| *v,                                               |l, n, d| gl::UniformMatrix4x3fv(l, n, gl::FALSE, d);
+    [[f64; 2]; 2]            => DMat2,   f64, |v| *v,                                               |l, n, d| gl::UniformMatrix2dv(l, n, gl::FALSE, d);
+    [[f64; 3]; 3]            => DMat3,   f64, |v| *v,                                               |l, n, d| gl::UniformMatrix3dv(l, n, gl::FALSE, d);
+    [[f64; 4]; 4]            => DMat4,   f64, |v| *v,                                               |l, n, d| gl::UniformMatrix4dv(l, n, gl::FALSE, d);
+    [[f64; 3]; 2]            => DMat2x3, f64, |v| *v,                                               |l, n, d| gl::UniformMatrix2x3dv(l, n, gl::FALSE, d);
+    [[f64; 4]; 2]            => DMat2x4, f64, |v| *v,                                               |l, n, d| gl::UniformMatrix2x4dv(l, n, gl::FALSE, d);
+    [[f64; 2]; 3]            => DMat3x2, f64, |v| *v,                                               |l, n, d| gl::UniformMatrix3x2dv(l, n, gl::FALSE, d);
+    [[f64; 4]; 3]            => DMat3x4, f64, |v| *v,                                               |l, n, d| gl::UniformMatrix3x4dv(l, n, gl::FALSE, d);
+    [[f64; 2]; 4]            => DMat4x2, f64, |v| *v,                                               |l, n, d| gl::UniformMatrix4x2dv(l, n, gl::FALSE, d);
+    [[f64; 3]; 4]            => DMat4x3, f64, |v| *v,                                               |l, n, d| gl::UniformMatrix4x3dv(l, n, gl::FALSE, d);
+);
+
+impl<T: UniformElement> Uniform for [T] {
+    fn uniform_type(&self) -> UniformType { T::element_type() }
+
+    fn array_len(&self) -> Option<usize> { Some(self.len()) }
+
+    fn set_uniform(&self, location: usize) {
+        T::set_uniform_array(self, location)
+    }
+}
+
+impl<T: UniformElement, const N: usize> Uniform for [T; N] {
+    fn uniform_type(&self) -> UniformType { T::element_type() }
+
+    fn array_len(&self) -> Option<usize> { Some(N) }
+
+    fn set_uniform(&self, location: usize) {
+        self[..].set_uniform(location)
+    }
+}
+
+impl<T: UniformElement> Uniform for Vec<T> {
+    fn uniform_type(&self) -> UniformType { T::element_type() }
+
+    fn array_len(&self) -> Option<usize> { Some(self.len()) }
+
+    fn set_uniform(&self, location: usize) {
+        self[..].set_uniform(location)
+    }
+}
@@ -0,0 +1,25 @@
+impl_uniform_via!(
+    ::glam::BVec2 => (bool, bool),             |v| (v.x, v.y);
+    ::glam::BVec3 => (bool, bool, bool),       |v| (v.x, v.y, v.z);
+    ::glam::BVec4 => (bool, bool, bool, bool), |v| (v.x, v.y, v.z, v.w);
+    ::glam::Vec2  => (f32, f32),               |v| (v.x, v.y);
+    ::glam::Vec3  => (f32, f32, f32),          |v| (v.x, v.y, v.z);
+    ::glam::Vec3A => (f32, f32, f32),          |v| (v.x, v.y, v.z);
+    ::glam::Vec4  => (f32, f32, f32, f32),     |v| (v.x, v.y, v.z, v.w);
+    ::glam::DVec2 => (f64, f64),               |v| (v.x, v.y);
+    ::glam::DVec3 => (f64, f64, f64),          |v| (v.x, v.y, v.z);
+    ::glam::DVec4 => (f64, f64, f64, f64),     |v| (v.x, v.y, v.z, v.w);
+    ::glam::IVec2 => (i32, i32),               |v| (v.x, v.y);
+    ::glam::IVec3 => (i32, i32, i32),          |v| (v.x, v.y, v.z);
+    ::glam::IVec4 => (i32, i32, i32, i32),     |v| (v.x, v.y, v.z, v.w);
+    ::glam::UVec2 => (u32, u32),               |v| (v.x, v.y);
+    ::glam::UVec3 => (u32, u32, u32),          |v| (v.x, v.y, v.z);
+    ::glam::UVec4 => (u32, u32, u32, u32),     |v| (v.x, v.y, v.z, v.w);
+    ::glam::Mat2  => [[f32; 2]; 2],            |v| v.to_cols_array_2d();
+    ::glam::Mat3  => [[f32; 3]; 3],            |v| v.to_cols_array_2d();
+    ::glam::Mat3A => [[f32; 3]; 3],            |v| v.to_cols_array_2d();
+    ::glam::Mat4  => [[f32; 4]; 4],            |v| v.to_cols_array_2d();
+    ::glam::DMat2 => [[f64; 2]; 2],            |v| v.to_cols_array_2d();
+    ::glam::DMat3 => [[f64; 3]; 3],            |v| v.to_cols_array_2d();
+    ::glam::DMat4 => [[f64; 4]; 4],            |v| v.to_cols_array_2d();
+);
@@ -0,0 +1,27 @@
+// mint's `ColumnMatrixRxC` has R rows and C columns, so it converts to `[[T; R]; C]`, the GLSL `matCxR`.
+impl_uniform_via!(
+    ::mint::Vector2<f32>         => (f32, f32),           |v| (v.x, v.y);
+    ::mint::Vector3<f32>         => (f32, f32, f32),      |v| (v.x, v.y, v.z);
+    ::mint::Vector4<f32>         => (f32, f32, f32, f32), |v| (v.x, v.y, v.z, v.w);
+    ::mint::Vector2<f64>         => (f64, f64),           |v| (v.x, v.y);
+    ::mint::Vector3<f64>         => (f64, f64, f64),      |v| (v.x, v.y, v.z);
+    ::mint::Vector4<f64>         => (f64, f64, f64, f64), |v| (v.x, v.y, v.z, v.w);
+    ::mint::Vector2<i32>         => (i32, i32),           |v| (v.x, v.y);
+    ::mint::Vector3<i32>         => (i32, i32, i32),      |v| (v.x, v.y, v.z);
+    ::mint::Vector4<i32>         => (i32, i32, i32, i32), |v| (v.x, v.y, v.z, v.w);
+    ::mint::Vector2<u32>         => (u32, u32),           |v| (v.x, v.y);
+    ::mint::Vector3<u32>         => (u32, u32, u32),      |v| (v.x, v.y, v.z);
+    ::mint::Vector4<u32>         => (u32, u32, u32, u32), |v| (v.x, v.y, v.z, v.w);
+    ::mint::ColumnMatrix2<f32>   => [[f32; 2]; 2],        |v| (*v).into();
+    ::mint::ColumnMatrix3<f32>   => [[f32; 3]; 3],        |v| (*v).into();
+    ::mint::ColumnMatrix4<f32>   => [[f32; 4]; 4],        |v| (*v).into();
+    ::mint::ColumnMatrix2x3<f32> => [[f32; 2]; 3],        |v| (*v).into();
+    ::mint::ColumnMatrix2x4<f32> => [[f32; 2]; 4],        |v| (*v).into();
+    ::mint::ColumnMatrix3x2<f32> => [[f32; 3]; 2],        |v| (*v).into();
+    ::mint::ColumnMatrix3x4<f32> => [[f32; 3]; 4],        |v| (*v).into();
+    ::mint::ColumnMatrix4x2<f32> => [[f32; 4]; 2],        |v| (*v).into();
+    ::mint::ColumnMatrix4x3<f32> => [[f32; 4]; 3],        |v| (*v).into();
+    ::mint::ColumnMatrix2<f64>   => [[f64; 2]; 2],        |v| (*v).into();
+    ::mint::ColumnMatrix3<f64>   => [[f64; 3]; 3],        |v| (*v).into();
+    ::mint::ColumnMatrix4<f64>   => [[f64; 4]; 4],        |v| (*v).into();
+);
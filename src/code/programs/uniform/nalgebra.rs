@@ -0,0 +1,30 @@
+// nalgebra matrices are column-major, so a `MatrixRxC` converts to `[[T; R]; C]`, the GLSL `matCxR`.
+impl_uniform_via!(
+    ::nalgebra::Vector2<f32>   => (f32, f32),           |v| (v.x, v.y);
+    ::nalgebra::Vector3<f32>   => (f32, f32, f32),      |v| (v.x, v.y, v.z);
+    ::nalgebra::Vector4<f32>   => (f32, f32, f32, f32), |v| (v.x, v.y, v.z, v.w);
+    ::nalgebra::Vector2<f64>   => (f64, f64),           |v| (v.x, v.y);
+    ::nalgebra::Vector3<f64>   => (f64, f64, f64),      |v| (v.x, v.y, v.z);
+    ::nalgebra::Vector4<f64>   => (f64, f64, f64, f64), |v| (v.x, v.y, v.z, v.w);
+    ::nalgebra::Vector2<i32>   => (i32, i32),           |v| (v.x, v.y);
+    ::nalgebra::Vector3<i32>   => (i32, i32, i32),      |v| (v.x, v.y, v.z);
+    ::nalgebra::Vector4<i32>   => (i32, i32, i32, i32), |v| (v.x, v.y, v.z, v.w);
+    ::nalgebra::Vector2<u32>   => (u32, u32),           |v| (v.x, v.y);
+    ::nalgebra::Vector3<u32>   => (u32, u32, u32),      |v| (v.x, v.y, v.z);
+    ::nalgebra::Vector4<u32>   => (u32, u32, u32, u32), |v| (v.x, v.y, v.z, v.w);
+    ::nalgebra::Point2<f32>    => (f32, f32),           |v| (v.x, v.y);
+    ::nalgebra::Point3<f32>    => (f32, f32, f32),      |v| (v.x, v.y, v.z);
+    ::nalgebra::Matrix2<f32>   => [[f32; 2]; 2],        |v| (*v).into();
+    ::nalgebra::Matrix3<f32>   => [[f32; 3]; 3],        |v| (*v).into();
+    ::nalgebra::Matrix4<f32>   => [[f32; 4]; 4],        |v| (*v).into();
+    ::nalgebra::Matrix2x3<f32> => [[f32; 2]; 3],        |v| (*v).into();
+    ::nalgebra::Matrix2x4<f32> => [[f32; 2]; 4],        |v| (*v).into();
+    ::nalgebra::Matrix3x2<f32> => [[f32; 3]; 2],        |v| (*v).into();
+    ::nalgebra::Matrix3x4<f32> => [[f32; 3]; 4],        |v| (*v).into();
+    ::nalgebra::Matrix4x2<f32> => [[f32; 4]; 2],        |v| (*v).into();
+    ::nalgebra::Matrix4x3<f32> => [[f32; 4]; 3],        |v| (*v).into();
+    ::nalgebra::Matrix2<f64>   => [[f64; 2]; 2],        |v| (*v).into();
+    ::nalgebra::Matrix3<f64>   => [[f64; 3]; 3],        |v| (*v).into();
+    ::nalgebra::Matrix4<f64>   => [[f64; 4]; 4],        |v| (*v).into();
+    ::nalgebra::Isometry3<f32> => [[f32; 4]; 4],        |v| v.to_homogeneous().into();
+);
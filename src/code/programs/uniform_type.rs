@@ -0,0 +1,127 @@
+/// GLSL types of uniforms and shader inputs and outputs.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+#[allow(missing_docs)]
+pub enum UniformType {
+    Float, Vec2, Vec3, Vec4,
+    Double, DVec2, DVec3, DVec4,
+    Int, IVec2, IVec3, IVec4,
+    UInt, UVec2, UVec3, UVec4,
+    Bool, BVec2, BVec3, BVec4,
+    Mat2, Mat3, Mat4, Mat2x3, Mat2x4, Mat3x2, Mat3x4, Mat4x2, Mat4x3,
+    DMat2, DMat3, DMat4, DMat2x3, DMat2x4, DMat3x2, DMat3x4, DMat4x2, DMat4x3,
+    /// A sampler of the specified `OpenGL` type, such as `GL_SAMPLER_2D`.
+    Sampler(u32),
+    /// An image of the specified `OpenGL` type, such as `GL_IMAGE_2D`.
+    Image(u32),
+    /// An atomic counter.
+    AtomicCounter,
+    /// Any other `OpenGL` type.
+    Other(u32)
+}
+
+const SAMPLERS : [u32; 38] = [
+    gl::SAMPLER_1D, gl::SAMPLER_2D, gl::SAMPLER_3D, gl::SAMPLER_CUBE, gl::SAMPLER_1D_SHADOW,
+    gl::SAMPLER_2D_SHADOW, gl::SAMPLER_1D_ARRAY, gl::SAMPLER_2D_ARRAY, gl::SAMPLER_1D_ARRAY_SHADOW,
+    gl::SAMPLER_2D_ARRAY_SHADOW, gl::SAMPLER_2D_MULTISAMPLE, gl::SAMPLER_2D_MULTISAMPLE_ARRAY,
+    gl::SAMPLER_CUBE_SHADOW, gl::SAMPLER_BUFFER, gl::SAMPLER_2D_RECT, gl::SAMPLER_2D_RECT_SHADOW,
+    gl::SAMPLER_CUBE_MAP_ARRAY, gl::SAMPLER_CUBE_MAP_ARRAY_SHADOW,
+    gl::INT_SAMPLER_1D, gl::INT_SAMPLER_2D, gl::INT_SAMPLER_3D, gl::INT_SAMPLER_CUBE,
+    gl::INT_SAMPLER_1D_ARRAY, gl::INT_SAMPLER_2D_ARRAY, gl::INT_SAMPLER_2D_MULTISAMPLE,
+    gl::INT_SAMPLER_2D_MULTISAMPLE_ARRAY, gl::INT_SAMPLER_BUFFER, gl::INT_SAMPLER_2D_RECT,
+    gl::UNSIGNED_INT_SAMPLER_1D, gl::UNSIGNED_INT_SAMPLER_2D, gl::UNSIGNED_INT_SAMPLER_3D,
+    gl::UNSIGNED_INT_SAMPLER_CUBE, gl::UNSIGNED_INT_SAMPLER_1D_ARRAY,
+    gl::UNSIGNED_INT_SAMPLER_2D_ARRAY, gl::UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE,
+    gl::UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY, gl::UNSIGNED_INT_SAMPLER_BUFFER,
+    gl::UNSIGNED_INT_SAMPLER_2D_RECT
+];
+
+const IMAGES : [u32; 33] = [
+    gl::IMAGE_1D, gl::IMAGE_2D, gl::IMAGE_3D, gl::IMAGE_2D_RECT, gl::IMAGE_CUBE, gl::IMAGE_BUFFER,
+    gl::IMAGE_1D_ARRAY, gl::IMAGE_2D_ARRAY, gl::IMAGE_CUBE_MAP_ARRAY, gl::IMAGE_2D_MULTISAMPLE,
+    gl::IMAGE_2D_MULTISAMPLE_ARRAY,
+    gl::INT_IMAGE_1D, gl::INT_IMAGE_2D, gl::INT_IMAGE_3D, gl::INT_IMAGE_2D_RECT, gl::INT_IMAGE_CUBE,
+    gl::INT_IMAGE_BUFFER, gl::INT_IMAGE_1D_ARRAY, gl::INT_IMAGE_2D_ARRAY,
+    gl::INT_IMAGE_CUBE_MAP_ARRAY, gl::INT_IMAGE_2D_MULTISAMPLE, gl::INT_IMAGE_2D_MULTISAMPLE_ARRAY,
+    gl::UNSIGNED_INT_IMAGE_1D, gl::UNSIGNED_INT_IMAGE_2D, gl::UNSIGNED_INT_IMAGE_3D,
+    gl::UNSIGNED_INT_IMAGE_2D_RECT, gl::UNSIGNED_INT_IMAGE_CUBE, gl::UNSIGNED_INT_IMAGE_BUFFER,
+    gl::UNSIGNED_INT_IMAGE_1D_ARRAY, gl::UNSIGNED_INT_IMAGE_2D_ARRAY,
+    gl::UNSIGNED_INT_IMAGE_CUBE_MAP_ARRAY, gl::UNSIGNED_INT_IMAGE_2D_MULTISAMPLE,
+    gl::UNSIGNED_INT_IMAGE_2D_MULTISAMPLE_ARRAY
+];
+
+impl UniformType {
+    /// Creates a `UniformType` from its `OpenGL` internal representation.
+    pub fn from_internal(type_: u32) -> Self {
+        match type_ {
+            gl::FLOAT             => UniformType::Float,
+            gl::FLOAT_VEC2        => UniformType::Vec2,
+            gl::FLOAT_VEC3        => UniformType::Vec3,
+            gl::FLOAT_VEC4        => UniformType::Vec4,
+            gl::DOUBLE            => UniformType::Double,
+            gl::DOUBLE_VEC2       => UniformType::DVec2,
+            gl::DOUBLE_VEC3       => UniformType::DVec3,
+            gl::DOUBLE_VEC4       => UniformType::DVec4,
+            gl::INT               => UniformType::Int,
+            gl::INT_VEC2          => UniformType::IVec2,
+            gl::INT_VEC3          => UniformType::IVec3,
+            gl::INT_VEC4          => UniformType::IVec4,
+            gl::UNSIGNED_INT      => UniformType::UInt,
+            gl::UNSIGNED_INT_VEC2 => UniformType::UVec2,
+            gl::UNSIGNED_INT_VEC3 => UniformType::UVec3,
+            gl::UNSIGNED_INT_VEC4 => UniformType::UVec4,
+            gl::BOOL              => UniformType::Bool,
+            gl::BOOL_VEC2         => UniformType::BVec2,
+            gl::BOOL_VEC3         => UniformType::BVec3,
+            gl::BOOL_VEC4         => UniformType::BVec4,
+            gl::FLOAT_MAT2        => UniformType::Mat2,
+            gl::FLOAT_MAT3        => UniformType::Mat3,
+            gl::FLOAT_MAT4        => UniformType::Mat4,
+            gl::FLOAT_MAT2x3      => UniformType::Mat2x3,
+            gl::FLOAT_MAT2x4      => UniformType::Mat2x4,
+            gl::FLOAT_MAT3x2      => UniformType::Mat3x2,
+            gl::FLOAT_MAT3x4      => UniformType::Mat3x4,
+            gl::FLOAT_MAT4x2      => UniformType::Mat4x2,
+            gl::FLOAT_MAT4x3      => UniformType::Mat4x3,
+            gl::DOUBLE_MAT2       => UniformType::DMat2,
+            gl::DOUBLE_MAT3       => UniformType::DMat3,
+            gl::DOUBLE_MAT4       => UniformType::DMat4,
+            gl::DOUBLE_MAT2x3     => UniformType::DMat2x3,
+            gl::DOUBLE_MAT2x4     => UniformType::DMat2x4,
+            gl::DOUBLE_MAT3x2     => UniformType::DMat3x2,
+            gl::DOUBLE_MAT3x4     => UniformType::DMat3x4,
+            gl::DOUBLE_MAT4x2     => UniformType::DMat4x2,
+            gl::DOUBLE_MAT4x3     => UniformType::DMat4x3,
+            gl::UNSIGNED_INT_ATOMIC_COUNTER => UniformType::AtomicCounter,
+            type_ if SAMPLERS.contains(&type_) => UniformType::Sampler(type_),
+            type_ if IMAGES.contains(&type_)   => UniformType::Image(type_),
+            type_ => UniformType::Other(type_)
+        }
+    }
+
+    /// Checks if a value of `value_type` can be set to a uniform of this type. Samplers and
+    /// images accept an `Int` unit.
+    pub fn accepts(&self, value_type: UniformType) -> bool {
+        match (self, value_type) {
+            (UniformType::Sampler(_), UniformType::Int) => true,
+            (UniformType::Image(_), UniformType::Int)   => true,
+            (uniform_type, value_type) => *uniform_type == value_type
+        }
+    }
+
+    /// Checks if it's a sampler type.
+    pub fn is_sampler(&self) -> bool {
+        matches!(self, UniformType::Sampler(_))
+    }
+
+    /// Checks if it's an image type.
+    pub fn is_image(&self) -> bool {
+        matches!(self, UniformType::Image(_))
+    }
+
+    /// Checks if it's a signed or unsigned integer scalar or vector type.
+    pub fn is_integer(&self) -> bool {
+        matches!(self,
+            UniformType::Int  | UniformType::IVec2 | UniformType::IVec3 | UniformType::IVec4 |
+            UniformType::UInt | UniformType::UVec2 | UniformType::UVec3 | UniformType::UVec4)
+    }
+}
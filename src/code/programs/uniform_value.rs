@@ -0,0 +1,113 @@
+use crate::{Uniform, UniformType};
+
+/// A `Uniform` value whose type is only known at runtime.
+#[derive(Clone,Copy,Debug,PartialEq)]
+#[allow(missing_docs)]
+pub enum UniformValue {
+    Bool(bool),
+    BVec2((bool, bool)),
+    BVec3((bool, bool, bool)),
+    BVec4((bool, bool, bool, bool)),
+    Float(f32),
+    Vec2((f32, f32)),
+    Vec3((f32, f32, f32)),
+    Vec4((f32, f32, f32, f32)),
+    Int(i32),
+    IVec2((i32, i32)),
+    IVec3((i32, i32, i32)),
+    IVec4((i32, i32, i32, i32)),
+    UInt(u32),
+    UVec2((u32, u32)),
+    UVec3((u32, u32, u32)),
+    UVec4((u32, u32, u32, u32)),
+    /// A column-major `mat2`.
+    Mat2([[f32; 2]; 2]),
+    /// A column-major `mat3`.
+    Mat3([[f32; 3]; 3]),
+    /// A column-major `mat4`.
+    Mat4([[f32; 4]; 4])
+}
+
+impl Uniform for UniformValue {
+    fn uniform_type(&self) -> UniformType {
+        match self {
+            UniformValue::Bool(_)  => UniformType::Bool,
+            UniformValue::BVec2(_) => UniformType::BVec2,
+            UniformValue::BVec3(_) => UniformType::BVec3,
+            UniformValue::BVec4(_) => UniformType::BVec4,
+            UniformValue::Float(_) => UniformType::Float,
+            UniformValue::Vec2(_)  => UniformType::Vec2,
+            UniformValue::Vec3(_)  => UniformType::Vec3,
+            UniformValue::Vec4(_)  => UniformType::Vec4,
+            UniformValue::Int(_)   => UniformType::Int,
+            UniformValue::IVec2(_) => UniformType::IVec2,
+            UniformValue::IVec3(_) => UniformType::IVec3,
+            UniformValue::IVec4(_) => UniformType::IVec4,
+            UniformValue::UInt(_)  => UniformType::UInt,
+            UniformValue::UVec2(_) => UniformType::UVec2,
+            UniformValue::UVec3(_) => UniformType::UVec3,
+            UniformValue::UVec4(_) => UniformType::UVec4,
+            UniformValue::Mat2(_)  => UniformType::Mat2,
+            UniformValue::Mat3(_)  => UniformType::Mat3,
+            UniformValue::Mat4(_)  => UniformType::Mat4
+        }
+    }
+
+    fn set_uniform(&self, location: usize) {
+        match self {
+            UniformValue::Bool(value)  => value.set_uniform(location),
+            UniformValue::BVec2(value) => value.set_uniform(location),
+            UniformValue::BVec3(value) => value.set_uniform(location),
+            UniformValue::BVec4(value) => value.set_uniform(location),
+            UniformValue::Float(value) => value.set_uniform(location),
+            UniformValue::Vec2(value)  => value.set_uniform(location),
+            UniformValue::Vec3(value)  => value.set_uniform(location),
+            UniformValue::Vec4(value)  => value.set_uniform(location),
+            UniformValue::Int(value)   => value.set_uniform(location),
+            UniformValue::IVec2(value) => value.set_uniform(location),
+            UniformValue::IVec3(value) => value.set_uniform(location),
+            UniformValue::IVec4(value) => value.set_uniform(location),
+            UniformValue::UInt(value)  => value.set_uniform(location),
+            UniformValue::UVec2(value) => value.set_uniform(location),
+            UniformValue::UVec3(value) => value.set_uniform(location),
+            UniformValue::UVec4(value) => value.set_uniform(location),
+            UniformValue::Mat2(value)  => value.set_uniform(location),
+            UniformValue::Mat3(value)  => value.set_uniform(location),
+            UniformValue::Mat4(value)  => value.set_uniform(location)
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($($type_:ty => $variant:ident),*) => {
+        $(
+            impl From<$type_> for UniformValue {
+                fn from(value: $type_) -> Self {
+                    UniformValue::$variant(value)
+                }
+            }
+        )*
+    }
+}
+
+impl_from!(
+    bool                     => Bool,
+    (bool, bool)             => BVec2,
+    (bool, bool, bool)       => BVec3,
+    (bool, bool, bool, bool) => BVec4,
+    f32                      => Float,
+    (f32, f32)               => Vec2,
+    (f32, f32, f32)          => Vec3,
+    (f32, f32, f32, f32)     => Vec4,
+    i32                      => Int,
+    (i32, i32)               => IVec2,
+    (i32, i32, i32)          => IVec3,
+    (i32, i32, i32, i32)     => IVec4,
+    u32                      => UInt,
+    (u32, u32)               => UVec2,
+    (u32, u32, u32)          => UVec3,
+    (u32, u32, u32, u32)     => UVec4,
+    [[f32; 2]; 2]            => Mat2,
+    [[f32; 3]; 3]            => Mat3,
+    [[f32; 4]; 4]            => Mat4
+);
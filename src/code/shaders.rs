@@ -1,9 +1,34 @@
 mod shader;
+mod shader_stage;
+mod shader_source;
+mod glsl_dialect;
+mod diagnostic;
+mod shader_error;
+mod preprocessor;
+#[cfg(feature = "naga")]
+mod naga_bridge;
+#[cfg(feature = "wgsl")]
+mod wgsl_bindings;
 mod vertex_shader;
+mod tess_control_shader;
+mod tess_evaluation_shader;
+mod geometry_shader;
 mod fragment_shader;
 mod compute_shader;
 
 pub use shader::Shader;
+pub use shader_stage::ShaderStage;
+pub use shader_source::ShaderSource;
+pub use glsl_dialect::GlslDialect;
+pub(crate) use glsl_dialect::FRAG_COLOR;
+pub use diagnostic::{Diagnostic, Severity};
+pub use shader_error::ShaderError;
+pub use preprocessor::*;
+#[cfg(feature = "wgsl")]
+pub use wgsl_bindings::{BindingKind, WgslBinding, WgslBindings};
 pub use vertex_shader::VertexShader;
+pub use tess_control_shader::TessControlShader;
+pub use tess_evaluation_shader::TessEvaluationShader;
+pub use geometry_shader::GeometryShader;
 pub use fragment_shader::FragmentShader;
 pub use compute_shader::ComputeShader;
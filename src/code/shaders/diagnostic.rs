@@ -0,0 +1,149 @@
+use crate::ShaderStage;
+use std::fmt;
+
+/// The severity of a `Diagnostic`.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,PartialOrd,Ord)]
+pub enum Severity {
+    /// An informative note.
+    Info,
+    /// A warning that doesn't prevent compilation.
+    Warning,
+    /// An error that prevents compilation.
+    Error
+}
+
+impl Severity {
+    fn from_word(word: &str) -> Option<Self> {
+        match word.to_lowercase().as_str() {
+            "error"          => Some(Severity::Error),
+            "warning"        => Some(Severity::Warning),
+            "info" | "note"  => Some(Severity::Info),
+            _                => None
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info    => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error   => write!(f, "error")
+        }
+    }
+}
+
+/// A message of a driver's compile log.
+#[derive(Clone,Debug,PartialEq)]
+pub struct Diagnostic {
+    /// The stage of the compiled shader.
+    pub stage    : ShaderStage,
+    /// The source string number, which is the file index of a `PreprocessedSource`.
+    pub source   : Option<usize>,
+    /// The file, if the source was preprocessed.
+    pub file     : Option<String>,
+    /// The line, starting at 1.
+    pub line     : Option<usize>,
+    /// The column, starting at 1. Only some drivers report it.
+    pub column   : Option<usize>,
+    /// The severity.
+    pub severity : Severity,
+    /// The message without the location and severity.
+    pub message  : String
+}
+
+impl Diagnostic {
+    /// Parses a compile log in the Mesa (`0:12(5): error: ...`), NVIDIA
+    /// (`0(12) : error C0000: ...`) or AMD/ANGLE (`ERROR: 0:12: ...`) formats. Source string
+    /// numbers are resolved to the `files`. Unrecognized lines become diagnostics without a
+    /// location.
+    pub fn parse_log(stage: ShaderStage, log: &str, files: &[String]) -> Vec<Self> {
+        log.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Self::parse_line(stage, line, files))
+            .collect()
+    }
+
+    fn parse_line(stage: ShaderStage, line: &str, files: &[String]) -> Self {
+        let mut rest = line.trim();
+        let mut severity = None;
+
+        // AMD and ANGLE: `ERROR: 0:12: message`.
+        if let Some(index) = rest.find(':') {
+            let word = &rest[..index];
+            if !word.is_empty() && word.chars().all(|c| c.is_ascii_uppercase()) {
+                if let Some(prefix) = Severity::from_word(word) {
+                    severity = Some(prefix);
+                    rest = rest[index + 1..].trim_start();
+                }
+            }
+        }
+
+        let (source, line_number, column, remaining) = Self::parse_location(rest);
+        rest = remaining.trim_start_matches(|c: char| c == ':' || c.is_whitespace());
+
+        // Mesa and NVIDIA: `error: message` and `error C0000: message`.
+        if severity.is_none() {
+            let word_end = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+            if let Some(word) = Severity::from_word(&rest[..word_end]) {
+                severity = Some(word);
+                rest = &rest[word_end..];
+                let code_end = rest.find(':').unwrap_or(0);
+                if rest[..code_end].trim().chars().all(|c| c.is_ascii_alphanumeric()) {
+                    rest = &rest[code_end..];
+                }
+                rest = rest.trim_start_matches(|c: char| c == ':' || c.is_whitespace());
+            }
+        }
+
+        let severity = severity.unwrap_or_else(|| {
+            let lowercase = line.to_lowercase();
+            if lowercase.contains("error") { Severity::Error }
+            else if lowercase.contains("warning") { Severity::Warning }
+            else { Severity::Info }
+        });
+        let file = source.and_then(|source| files.get(source)).cloned();
+        let message = rest.trim().to_string();
+        Self { stage, source, file, line: line_number, column, severity, message }
+    }
+
+    /// Parses `0:12(5)`, `0:12` or `0(12)`, returning the unparsed remainder.
+    fn parse_location(text: &str) -> (Option<usize>, Option<usize>, Option<usize>, &str) {
+        fn number(text: &str) -> Option<(usize, &str)> {
+            let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 { return None }
+            Some((text[..digits].parse().ok()?, &text[digits..]))
+        }
+        let (source, rest) = match number(text) {
+            Some(parsed) => parsed,
+            None => return (None, None, None, text)
+        };
+        if let Some(rest) = rest.strip_prefix(':') {
+            if let Some((line, rest)) = number(rest) {
+                if let Some((column, after)) = rest.strip_prefix('(').and_then(number) {
+                    if let Some(after) = after.strip_prefix(')') {
+                        return (Some(source), Some(line), Some(column), after)
+                    }
+                }
+                return (Some(source), Some(line), None, rest)
+            }
+        } else if let Some((line, after)) = rest.strip_prefix('(').and_then(number) {
+            if let Some(after) = after.strip_prefix(')') {
+                return (Some(source), Some(line), None, after)
+            }
+        }
+        (None, None, None, text)
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = self.file.clone().unwrap_or_else(|| format!("{} shader", self.stage.name()));
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{}:{}:{}: ", file, line, column)?,
+            (Some(line), None)         => write!(f, "{}:{}: ", file, line)?,
+            _                          => write!(f, "{}: ", file)?
+        }
+        write!(f, "{}: {}", self.severity, self.message)
+    }
+}
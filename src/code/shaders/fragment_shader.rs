@@ -1,6 +1,11 @@
 use crate::prelude::*;
 use crate::code::shaders::shader::Shader;
 use crate::Context;
+use crate::ShaderSource;
+#[cfg(feature = "naga")]
+use crate::ShaderStage;
+#[cfg(feature = "wgsl")]
+use crate::WgslBindings;
 
 /// A fragment shader representation.
 #[derive(Shrinkwrap)]
@@ -9,9 +14,25 @@ pub struct FragmentShader {
 }
 
 impl FragmentShader {
-    /// Creates a new `FragmentShader` from a source code.
-    pub fn new(context:&Context, source: &str) -> Result<Self, String> {
+    /// Creates a new `FragmentShader` from a source code or a `PreprocessedSource`.
+    pub fn new<'a>(context:&Context, source: impl Into<ShaderSource<'a>>) -> Result<Self, String> {
         let shader = Shader::new(context, gl::FRAGMENT_SHADER, source)?;
         Ok(Self{shader})
     }
-}
\ No newline at end of file
+
+    /// Creates a new `FragmentShader` from the `entry_point` of a SPIR-V module, cross-compiled with
+    /// naga if the driver doesn't support `ARB_gl_spirv`.
+    #[cfg(feature = "naga")]
+    pub fn from_spirv(context:&Context, spirv: &[u8], entry_point: &str) -> Result<Self, String> {
+        let shader = Shader::from_spirv(context, ShaderStage::Fragment, spirv, entry_point)?;
+        Ok(Self{shader})
+    }
+
+    /// Creates a new `FragmentShader` from the `entry_point` of a WGSL source, returning the binding
+    /// points assigned to its resources.
+    #[cfg(feature = "wgsl")]
+    pub fn from_wgsl(context:&Context, source: &str, entry_point: &str) -> Result<(Self, WgslBindings), String> {
+        let (shader, bindings) = Shader::from_wgsl(context, ShaderStage::Fragment, source, entry_point)?;
+        Ok((Self{shader}, bindings))
+    }
+}
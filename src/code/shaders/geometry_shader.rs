@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::code::shaders::shader::Shader;
+use crate::Context;
+use crate::ShaderSource;
+
+/// A geometry shader representation.
+#[derive(Shrinkwrap)]
+pub struct GeometryShader {
+    shader : Shader
+}
+
+impl GeometryShader {
+    /// Creates a new `GeometryShader` from a source code or a `PreprocessedSource`.
+    pub fn new<'a>(context:&Context, source: impl Into<ShaderSource<'a>>) -> Result<Self, String> {
+        let shader = Shader::new(context, gl::GEOMETRY_SHADER, source)?;
+        Ok(Self{shader})
+    }
+}
@@ -0,0 +1,192 @@
+use crate::ShaderStage;
+
+/// The GLSL flavor accepted by a `Context`.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub enum GlslDialect {
+    /// Desktop core profile GLSL with the specified version, e.g. `450`.
+    Core(u32),
+    /// GLSL ES with the specified version, e.g. `310`.
+    Es(u32),
+    /// WebGL2, which accepts GLSL ES 3.00.
+    WebGl2
+}
+
+/// The fragment output declared in place of `gl_FragColor`. Versions without output layouts get
+/// it bound to location `0` when linking.
+pub(crate) const FRAG_COLOR : &str = "gpu_FragColor";
+
+/// Identifiers of GLSL 1.x replaced by their GLSL 3.x equivalents.
+const RENAMES : [(&str, &str); 8] = [
+    ("texture2D",      "texture"),
+    ("texture3D",      "texture"),
+    ("textureCube",    "texture"),
+    ("shadow2D",       "texture"),
+    ("texture2DProj",  "textureProj"),
+    ("texture2DLod",   "textureLod"),
+    ("textureCubeLod", "textureLod"),
+    ("gl_FragColor",   "gpu_FragColor")
+];
+
+/// Types without a default precision in GLSL ES.
+const ES_PRECISION_TYPES : [&str; 13] = [
+    "sampler3D", "sampler2DShadow", "samplerCubeShadow", "sampler2DArray", "sampler2DArrayShadow",
+    "isampler2D", "isampler3D", "isamplerCube", "isampler2DArray",
+    "usampler2D", "usampler3D", "usamplerCube", "usampler2DArray"
+];
+
+/// Image types without a default precision in GLSL ES 3.10 and later.
+const ES_PRECISION_IMAGE_TYPES : [&str; 12] = [
+    "image2D", "image3D", "imageCube", "image2DArray",
+    "iimage2D", "iimage3D", "iimageCube", "iimage2DArray",
+    "uimage2D", "uimage3D", "uimageCube", "uimage2DArray"
+];
+
+impl GlslDialect {
+    /// Parses the `GL_SHADING_LANGUAGE_VERSION` string, e.g. `4.60 NVIDIA`,
+    /// `OpenGL ES GLSL ES 3.20` or `WebGL GLSL ES 3.00`.
+    pub fn from_version_string(version: &str) -> Self {
+        if version.contains("WebGL") {
+            return GlslDialect::WebGl2
+        }
+        let number = version.split_whitespace()
+            .find(|word| word.contains('.') && word.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(false))
+            .and_then(|word| {
+                let mut parts = word.split('.');
+                let major : u32 = parts.next()?.parse().ok()?;
+                let minor : String = parts.next()?.chars().take_while(|c| c.is_ascii_digit()).take(2).collect();
+                let minor : u32 = format!("{:0<2}", minor).parse().ok()?;
+                Some(major * 100 + minor)
+            });
+        if version.contains(" ES") {
+            GlslDialect::Es(number.unwrap_or(300))
+        } else {
+            GlslDialect::Core(number.unwrap_or(330))
+        }
+    }
+
+    /// Checks if it's GLSL ES or WebGL2.
+    pub fn is_es(&self) -> bool {
+        !matches!(self, GlslDialect::Core(_))
+    }
+
+    /// Gets the version number, e.g. `300` for WebGL2.
+    pub fn version(&self) -> u32 {
+        match self {
+            GlslDialect::Core(version) | GlslDialect::Es(version) => *version,
+            GlslDialect::WebGl2 => 300
+        }
+    }
+
+    /// Checks if it accepts the `in` and `out` storage qualifiers of GLSL 1.30 and GLSL ES 3.00,
+    /// which replace `attribute`, `varying` and `gl_FragColor`.
+    pub fn has_in_out(&self) -> bool {
+        self.version() >= if self.is_es() { 300 } else { 130 }
+    }
+
+    /// Checks if `layout(location = n)` can be used on fragment outputs, from GLSL 3.30 and
+    /// GLSL ES 3.00 on.
+    pub fn has_output_layouts(&self) -> bool {
+        self.version() >= if self.is_es() { 300 } else { 330 }
+    }
+
+    /// Gets the directives inserted before a versionless source: `#version`, the extensions the
+    /// `stage` needs and, for GLSL ES, default precisions. Profiles only exist from GLSL 1.50 on,
+    /// so older versions don't name one.
+    pub fn header(&self, stage: Option<ShaderStage>) -> String {
+        let mut header = if self.is_es() {
+            format!("#version {} es\n", self.version())
+        } else if self.version() < 150 {
+            format!("#version {}\n", self.version())
+        } else {
+            format!("#version {} core\n", self.version())
+        };
+        if self.is_es() && self.version() < 320 {
+            match stage {
+                Some(ShaderStage::Geometry) => header.push_str("#extension GL_EXT_geometry_shader : require\n"),
+                Some(ShaderStage::TessControl) | Some(ShaderStage::TessEvaluation) => header.push_str("#extension GL_EXT_tessellation_shader : require\n"),
+                _ => ()
+            }
+        }
+        if self.is_es() {
+            header.push_str("precision highp float;\nprecision highp int;\n");
+            for type_ in ES_PRECISION_TYPES.iter() {
+                header.push_str(&format!("precision highp {};\n", type_));
+            }
+            if self.version() >= 310 {
+                for type_ in ES_PRECISION_IMAGE_TYPES.iter() {
+                    header.push_str(&format!("precision highp {};\n", type_));
+                }
+            }
+        }
+        header
+    }
+
+    /// Adapts a versionless source to this dialect. It inserts the `header` and, if the dialect
+    /// has `in` and `out`, declares the fragment output replacing `gl_FragColor` and renames
+    /// GLSL 1.x built-in functions. If the `stage` is known, `attribute` and `varying` are then
+    /// replaced by `in` and `out`. A `#line` directive keeps the line numbers of the original
+    /// source.
+    pub fn adapt(&self, stage: Option<ShaderStage>, source: &str) -> String {
+        let mut output = self.header(stage);
+        if !self.has_in_out() {
+            output.push_str("#line 1 0\n");
+            output.push_str(source);
+            return output
+        }
+        if contains_identifier(source, "gl_FragColor") {
+            if self.has_output_layouts() {
+                output.push_str(&format!("layout(location = 0) out vec4 {};\n", FRAG_COLOR));
+            } else {
+                output.push_str(&format!("out vec4 {};\n", FRAG_COLOR));
+            }
+        }
+        output.push_str("#line 1 0\n");
+        let (attribute, varying) = match stage {
+            Some(ShaderStage::Vertex)   => (Some("in"), Some("out")),
+            Some(ShaderStage::Fragment) => (None, Some("in")),
+            _ => (None, None)
+        };
+        output.push_str(&replace_identifiers(source, |identifier| {
+            match identifier {
+                "attribute" => attribute,
+                "varying"   => varying,
+                identifier  => RENAMES.iter().find(|(from, _)| *from == identifier).map(|(_, to)| *to)
+            }
+        }));
+        output
+    }
+}
+
+fn is_identifier_character(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn contains_identifier(source: &str, identifier: &str) -> bool {
+    let mut found = false;
+    replace_identifiers(source, |candidate| {
+        found |= candidate == identifier;
+        None
+    });
+    found
+}
+
+/// Replaces the identifiers for which `replacement` returns a new name.
+fn replace_identifiers<'a>(source: &str, mut replacement: impl FnMut(&str) -> Option<&'a str>) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut start = None;
+    for (index, c) in source.char_indices().chain(std::iter::once((source.len(), ' '))) {
+        match (start, is_identifier_character(c)) {
+            (None, true) => start = Some(index),
+            (Some(begin), false) => {
+                let identifier = &source[begin..index];
+                output.push_str(replacement(identifier).unwrap_or(identifier));
+                start = None;
+            },
+            _ => ()
+        }
+        if start.is_none() && index < source.len() {
+            output.push(c);
+        }
+    }
+    output
+}
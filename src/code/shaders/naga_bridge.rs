@@ -0,0 +1,203 @@
+use crate::{GlslDialect, ShaderError, ShaderSource, ShaderStage};
+#[cfg(feature = "wgsl")]
+use crate::{BindingKind, WgslBinding, WgslBindings};
+use naga::back::glsl;
+use naga::valid::{Capabilities, ModuleInfo, ValidationFlags, Validator};
+use std::borrow::Cow;
+#[cfg(feature = "wgsl")]
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+
+fn naga_stage(stage: ShaderStage) -> Result<naga::ShaderStage, String> {
+    match stage {
+        ShaderStage::Vertex   => Ok(naga::ShaderStage::Vertex),
+        ShaderStage::Fragment => Ok(naga::ShaderStage::Fragment),
+        ShaderStage::Compute  => Ok(naga::ShaderStage::Compute),
+        _ => Err(format!("naga doesn't support {} shaders", stage.name()))
+    }
+}
+
+fn validate_module(module: &naga::Module) -> Result<ModuleInfo, naga::WithSpan<naga::valid::ValidationError>> {
+    Validator::new(ValidationFlags::all(), Capabilities::all()).validate(module)
+}
+
+/// Joins the messages of an error and its sources.
+fn message(error: &dyn Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(error) = source {
+        message.push_str(": ");
+        message.push_str(&error.to_string());
+        source = error.source();
+    }
+    message.replace('\n', " ")
+}
+
+/// Maps a physical line of `text` to the source string and line set by `#line` directives.
+fn logical_location(text: &str, physical_line: usize) -> (usize, usize) {
+    let (mut source, mut offset) = (0, 0i64);
+    for (index, line) in text.lines().take(physical_line.saturating_sub(1)).enumerate() {
+        let mut words = line.split_whitespace();
+        if words.next() != Some("#line") {
+            continue
+        }
+        if let Some(Ok(line)) = words.next().map(str::parse::<i64>) {
+            // The line after the directive, physical `index + 2`, is `line`.
+            offset = line - (index as i64 + 2);
+            if let Some(Ok(string)) = words.next().map(str::parse) {
+                source = string;
+            }
+        }
+    }
+    (source, (physical_line as i64 + offset).max(1) as usize)
+}
+
+/// Formats an error as a Mesa log line, which `Diagnostic::parse_log` understands.
+fn log_line(text: &str, span: naga::Span, message: &str) -> String {
+    if !span.is_defined() {
+        return format!("error: {}", message)
+    }
+    let location = span.location(text);
+    let (source, line) = logical_location(text, location.line_number as usize);
+    format!("{}:{}({}): error: {}", source, line, location.line_position, message)
+}
+
+/// Parses and validates a GLSL `source` without a GPU. naga only accepts `#version 440`, `450`
+/// and `460` sources, so `Versionless` sources are validated as `#version 450`.
+pub(crate) fn validate(stage: ShaderStage, source: ShaderSource<'_>) -> Result<(), ShaderError> {
+    let error = |log: &str| ShaderError::new(stage, log, &source.files(), source.contents());
+    let options = naga::front::glsl::Options::from(naga_stage(stage).map_err(|log| error(&log))?);
+    let text = match source {
+        ShaderSource::Versionless(text) => Cow::Owned(format!("#version 450\n#line 1 0\n{}", text)),
+        _ => Cow::Borrowed(source.text())
+    };
+    let log = match naga::front::glsl::Frontend::default().parse(&options, &text) {
+        Ok(module) => match validate_module(&module) {
+            Ok(_) => return Ok(()),
+            Err(validation) => {
+                let span = validation.spans().next().map(|(span, _)| *span).unwrap_or_default();
+                log_line(&text, span, &message(&validation.into_inner()))
+            }
+        },
+        Err(errors) => errors.iter()
+            .map(|parse| log_line(&text, parse.meta, &message(parse)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    Err(error(&log))
+}
+
+fn glsl_version(dialect: GlslDialect) -> glsl::Version {
+    match dialect {
+        GlslDialect::Core(version) => glsl::Version::Desktop(version as u16),
+        GlslDialect::Es(version)   => glsl::Version::new_gles(version as u16),
+        GlslDialect::WebGl2        => glsl::Version::Embedded { version: 300, is_webgl: true }
+    }
+}
+
+/// Translates the `entry_point` of a SPIR-V module to the `dialect`. SPIR-V conventions for
+/// `OpenGL` are assumed, so coordinates aren't adjusted and each resource keeps its `binding` as
+/// its binding point, as with `ARB_gl_spirv`. Modules using several descriptor sets are rejected.
+pub(crate) fn cross_compile(stage: ShaderStage, spirv: &[u8], entry_point: &str, dialect: GlslDialect) -> Result<String, String> {
+    let shader_stage = naga_stage(stage)?;
+    let options = naga::front::spv::Options { adjust_coordinate_space: false, ..Default::default() };
+    let module = naga::front::spv::parse_u8_slice(spirv, &options)
+        .map_err(|error| format!("Invalid SPIR-V module: {}", message(&error)))?;
+    let info = validate_module(&module)
+        .map_err(|error| format!("Invalid SPIR-V module: {}", message(&error.into_inner())))?;
+    // `OpenGL` has a single set of binding points, so descriptor sets can't be told apart.
+    let mut set = None;
+    let mut binding_map = glsl::BindingMap::default();
+    for (_, variable) in module.global_variables.iter() {
+        if let Some(resource_binding) = &variable.binding {
+            match set {
+                Some(set) if set != resource_binding.group => return Err(format!("Descriptor sets {} and {} are used, but only a single set can be mapped to OpenGL binding points", set, resource_binding.group)),
+                _ => set = Some(resource_binding.group)
+            }
+            let binding = u8::try_from(resource_binding.binding)
+                .map_err(|_| format!("Binding {} of `{}` is too large", resource_binding.binding, variable.name.clone().unwrap_or_default()))?;
+            binding_map.insert(resource_binding.clone(), binding);
+        }
+    }
+    let version = glsl_version(dialect);
+    let options = glsl::Options { version, writer_flags: glsl::WriterFlags::empty(), binding_map, ..Default::default() };
+    let pipeline_options = glsl::PipelineOptions { shader_stage, entry_point: entry_point.to_string(), multiview: None };
+    let mut output = String::new();
+    glsl::Writer::new(&mut output, &module, &info, &options, &pipeline_options, Default::default())
+        .and_then(|mut writer| writer.write())
+        .map_err(|error| format!("Failed to translate SPIR-V to GLSL {}: {}", dialect.version(), message(&error)))?;
+    Ok(output)
+}
+
+/// Translates the `entry_point` of a WGSL `source` to the `dialect`, assigning the binding points
+/// described by `WgslBindings`. Clip space isn't adjusted, so depth keeps WGSL's `[0, 1]` range.
+#[cfg(feature = "wgsl")]
+pub(crate) fn translate_wgsl(stage: ShaderStage, source: &str, entry_point: &str, dialect: GlslDialect) -> Result<(String, WgslBindings), String> {
+    let shader_stage = naga_stage(stage)?;
+    let module = naga::front::wgsl::parse_str(source)
+        .map_err(|error| error.emit_to_string(source))?;
+    let info = validate_module(&module)
+        .map_err(|error| error.emit_to_string(source))?;
+
+    let mut resources : Vec<_> = module.global_variables.iter()
+        .filter_map(|(handle, variable)| variable.binding.clone().map(|binding| (binding, handle, variable)))
+        .collect();
+    resources.sort_by_key(|(binding, _, _)| (binding.group, binding.binding));
+
+    let mut counters = HashMap::new();
+    let mut binding_map = glsl::BindingMap::default();
+    let mut bindings = Vec::new();
+    for (resource_binding, handle, variable) in &resources {
+        let kind = match (variable.space, &module.types[variable.ty].inner) {
+            (naga::AddressSpace::Uniform, _)                => BindingKind::UniformBuffer,
+            (naga::AddressSpace::Storage { .. }, _)         => BindingKind::StorageBuffer,
+            (_, naga::TypeInner::Sampler { .. })            => BindingKind::Sampler,
+            (_, naga::TypeInner::Image { class: naga::ImageClass::Storage { .. }, .. }) => BindingKind::StorageImage,
+            (_, naga::TypeInner::Image { .. })              => BindingKind::Texture,
+            _ => return Err(format!("Unsupported resource `{}`", variable.name.clone().unwrap_or_default()))
+        };
+        let unit = if kind == BindingKind::Sampler {
+            None
+        } else {
+            let counter = counters.entry(kind).or_insert(0usize);
+            let unit = *counter;
+            *counter += 1;
+            binding_map.insert(resource_binding.clone(), u8::try_from(unit).map_err(|_| "Too many resources of the same kind".to_string())?);
+            Some(unit)
+        };
+        let name = variable.name.clone().unwrap_or_else(|| format!("group_{}_binding_{}", resource_binding.group, resource_binding.binding));
+        bindings.push((*handle, WgslBinding { name, group: resource_binding.group, binding: resource_binding.binding, kind, unit, texture_units: Vec::new(), glsl_names: Vec::new() }));
+    }
+
+    let version = glsl_version(dialect);
+    let options = glsl::Options { version, writer_flags: glsl::WriterFlags::empty(), binding_map, ..Default::default() };
+    let pipeline_options = glsl::PipelineOptions { shader_stage, entry_point: entry_point.to_string(), multiview: None };
+    let mut output = String::new();
+    let reflection = glsl::Writer::new(&mut output, &module, &info, &options, &pipeline_options, Default::default())
+        .and_then(|mut writer| writer.write())
+        .map_err(|error| format!("Failed to translate WGSL to GLSL {}: {}", dialect.version(), message(&error)))?;
+
+    // GLSL combines textures and samplers, so samplers share the units of their textures. The
+    // writer already rejects textures sampled with several samplers. The combined samplers are
+    // sorted by name so the result doesn't depend on the map's order.
+    let units : HashMap<_, _> = bindings.iter().map(|(handle, binding)| (*handle, binding.unit)).collect();
+    let mut texture_mapping : Vec<_> = reflection.texture_mapping.iter().collect();
+    texture_mapping.sort_by_key(|(name, _)| *name);
+    for (handle, binding) in &mut bindings {
+        binding.glsl_names = reflection.uniforms.get(handle).cloned().into_iter().collect();
+        for (name, mapping) in &texture_mapping {
+            if mapping.texture == *handle {
+                binding.glsl_names.push(name.to_string());
+            } else if mapping.sampler == Some(*handle) && binding.kind == BindingKind::Sampler {
+                binding.texture_units.extend(units.get(&mapping.texture).copied().flatten());
+            }
+        }
+        binding.texture_units.sort_unstable();
+        binding.texture_units.dedup();
+        if binding.kind == BindingKind::Sampler {
+            binding.unit = binding.texture_units.first().copied();
+        }
+    }
+    Ok((output, WgslBindings::new(bindings.into_iter().map(|(_, binding)| binding).collect())))
+}
@@ -0,0 +1,186 @@
+mod source_provider;
+mod preprocessed_source;
+
+pub use source_provider::*;
+pub use preprocessed_source::*;
+
+use crate::{GlslDialect, ShaderStage};
+
+/// Resolves `#include "..."` directives from a `SourceProvider`, injects `#define`s after the
+/// `#version` directive and emits `#line` directives pointing back to the original files.
+pub struct Preprocessor<'a> {
+    provider : &'a dyn SourceProvider,
+    defines  : Vec<(String, String)>,
+    dialect  : Option<GlslDialect>,
+    stage    : Option<ShaderStage>
+}
+
+impl<'a> Preprocessor<'a> {
+    /// Creates a `Preprocessor` resolving includes from the `provider`.
+    pub fn new(provider: &'a dyn SourceProvider) -> Self {
+        let defines = Vec::new();
+        let dialect = None;
+        let stage   = None;
+        Self { provider, defines, dialect, stage }
+    }
+
+    /// Adds `#define name value`.
+    pub fn with_define(mut self, name: &str, value: &str) -> Self {
+        self.defines.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adapts versionless sources to the `GlslDialect`, usually `context.glsl_dialect()`. Unless
+    /// the stage is set with `with_stage`, stage specific extensions aren't inserted and
+    /// `attribute` and `varying` are kept.
+    pub fn with_dialect(mut self, dialect: Option<GlslDialect>) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Sets the `ShaderStage` versionless sources are adapted for.
+    pub fn with_stage(mut self, stage: Option<ShaderStage>) -> Self {
+        self.stage = stage;
+        self
+    }
+
+    /// Gets the defines.
+    pub fn defines(&self) -> &[(String, String)] { &self.defines }
+
+    /// Preprocesses the file at `path`.
+    pub fn process(&self, path: &str) -> Result<PreprocessedSource, String> {
+        let source = self.provider.source(path)?;
+        self.process_source(path, &source)
+    }
+
+    /// Preprocesses a `source` named `path`. Includes are resolved relative to `path`.
+    pub fn process_source(&self, path: &str, source: &str) -> Result<PreprocessedSource, String> {
+        let mut output = String::new();
+        let mut lines  = source.lines().enumerate();
+
+        // `#version` must come before anything but comments, so the defines go right after it.
+        let version_line = Self::version_line(source);
+        let has_version  = version_line.is_some();
+        let mut first_line = 1;
+        if let Some((index, line)) = version_line.and_then(|index| lines.nth(index)) {
+            output.push_str(line);
+            output.push('\n');
+            first_line = index + 2;
+        }
+        for (name, value) in self.defines.iter() {
+            output.push_str(&format!("#define {} {}\n", name, value));
+        }
+        output.push_str(&format!("#line {} 0\n", first_line));
+
+        let mut expansion = Expansion {
+            stack    : vec![path.to_string()],
+            files    : vec![path.to_string()],
+            contents : vec![source.to_string()],
+            included : Vec::new(),
+            output
+        };
+        for (index, line) in lines {
+            self.process_line(line, index + 1, 0, &mut expansion)?;
+        }
+        let output = match self.dialect {
+            Some(dialect) if !has_version => dialect.adapt(self.stage, &expansion.output),
+            _ => expansion.output
+        };
+        Ok(PreprocessedSource::new(output, expansion.files, expansion.contents))
+    }
+
+    /// Finds the index of the line with the `#version` directive, skipping the comments and
+    /// whitespace allowed before it.
+    fn version_line(source: &str) -> Option<usize> {
+        let mut rest = source;
+        loop {
+            let trimmed = rest.trim_start();
+            if let Some(comment) = trimmed.strip_prefix("//") {
+                rest = comment.find('\n').map(|end| &comment[end..]).unwrap_or("");
+            } else if let Some(comment) = trimmed.strip_prefix("/*") {
+                rest = &comment[comment.find("*/")? + 2..];
+            } else if trimmed.starts_with("#version") {
+                let offset = source.len() - trimmed.len();
+                return Some(source[..offset].matches('\n').count())
+            } else {
+                return None
+            }
+        }
+    }
+
+    fn process_line(&self, line: &str, line_number: usize, file: usize, expansion: &mut Expansion) -> Result<(), String> {
+        let trimmed = line.trim_start();
+        // An empty line keeps the following lines at the same line number.
+        if trimmed.starts_with("#pragma") && trimmed["#pragma".len()..].trim() == "once" {
+            expansion.output.push('\n');
+            return Ok(())
+        }
+        if !trimmed.starts_with("#include") {
+            expansion.output.push_str(line);
+            expansion.output.push('\n');
+            return Ok(())
+        }
+        let current = expansion.stack.last().cloned().unwrap_or_default();
+        let argument = trimmed["#include".len()..].trim();
+        let include = if argument.len() >= 2 && argument.starts_with('"') && argument.ends_with('"') {
+            &argument[1..argument.len() - 1]
+        } else {
+            return Err(format!("{}:{}: malformed #include, expected #include \"path\"", current, line_number))
+        };
+        let path = Self::resolve(&current, include);
+        if expansion.stack.contains(&path) {
+            return Err(format!("{}:{}: {} includes itself", current, line_number, path))
+        }
+        let source = self.provider.source(&path).map_err(|error| format!("{}:{}: {}", current, line_number, error))?;
+        let once = source.lines().any(|line| {
+            let line = line.trim();
+            line.starts_with("#pragma") && line["#pragma".len()..].trim() == "once"
+        });
+        if once && expansion.included.contains(&path) {
+            expansion.output.push_str(&format!("#line {} {}\n", line_number + 1, file));
+            return Ok(())
+        }
+        expansion.included.push(path.clone());
+
+        let include_file = expansion.files.len();
+        expansion.files.push(path.clone());
+        expansion.contents.push(source.clone());
+        expansion.stack.push(path);
+        expansion.output.push_str(&format!("#line 1 {}\n", include_file));
+        for (index, line) in source.lines().enumerate() {
+            self.process_line(line, index + 1, include_file, expansion)?;
+        }
+        expansion.stack.pop();
+        expansion.output.push_str(&format!("#line {} {}\n", line_number + 1, file));
+        Ok(())
+    }
+
+    /// Resolves `include` relative to the directory of `current`, collapsing `.` and `..`.
+    fn resolve(current: &str, include: &str) -> String {
+        let mut components : Vec<&str> = match current.rfind('/') {
+            Some(index) if !include.starts_with('/') => current[..index].split('/').collect(),
+            _ => Vec::new()
+        };
+        for component in include.split('/') {
+            match component {
+                "" | "." => (),
+                ".." => { components.pop(); },
+                component => components.push(component)
+            }
+        }
+        components.join("/")
+    }
+}
+
+/// The state of an expansion in progress.
+struct Expansion {
+    /// Files being included, used to detect cycles.
+    stack    : Vec<String>,
+    /// Files indexed by their source string number.
+    files    : Vec<String>,
+    /// Contents of the `files`.
+    contents : Vec<String>,
+    /// Files already included, used by `#pragma once`.
+    included : Vec<String>,
+    output   : String
+}
@@ -0,0 +1,47 @@
+/// A source produced by a `Preprocessor`. Its `#line` directives number each file, so driver logs
+/// can be mapped back to the original files.
+#[derive(Clone,Debug,PartialEq)]
+pub struct PreprocessedSource {
+    source   : String,
+    files    : Vec<String>,
+    contents : Vec<String>
+}
+
+impl PreprocessedSource {
+    pub(crate) fn new(source: String, files: Vec<String>, contents: Vec<String>) -> Self {
+        Self { source, files, contents }
+    }
+
+    /// Gets the preprocessed source.
+    pub fn source(&self) -> &str { &self.source }
+
+    /// Gets the paths of the files, indexed by the source string numbers used in `#line`.
+    pub fn files(&self) -> &[String] { &self.files }
+
+    /// Gets the original contents of the `files`.
+    pub fn contents(&self) -> &[String] { &self.contents }
+
+    /// Replaces the source string numbers at the beginning of each log line with the file paths,
+    /// e.g. `0:12(5): error` becomes `main.glsl:12(5): error`. Mesa, NVIDIA and AMD/ANGLE
+    /// formats are recognized.
+    pub fn map_log(&self, log: &str) -> String {
+        log.lines().map(|line| self.map_line(line)).collect::<Vec<_>>().join("\n")
+    }
+
+    fn map_line(&self, line: &str) -> String {
+        // AMD and ANGLE prefix the location with the severity, e.g. `ERROR: 0:12: ...`.
+        let (prefix, rest) = match line.find(": ") {
+            Some(index) if line[..index].chars().all(|c| c.is_ascii_uppercase()) && index > 0 => line.split_at(index + 2),
+            _ => ("", line)
+        };
+        let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+        let separator = rest[digits..].chars().next();
+        if digits == 0 || !(separator == Some(':') || separator == Some('(')) {
+            return line.to_string()
+        }
+        match rest[..digits].parse::<usize>().ok().and_then(|index| self.files.get(index)) {
+            Some(file) => format!("{}{}{}", prefix, file, &rest[digits..]),
+            None => line.to_string()
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// Provides the sources resolved by `#include` directives.
+pub trait SourceProvider {
+    /// Gets the source at `path`, which uses `/` as separator.
+    fn source(&self, path: &str) -> Result<String, String>;
+}
+
+/// A `SourceProvider` reading files from a directory.
+pub struct DirectorySource {
+    directory : PathBuf
+}
+
+impl DirectorySource {
+    /// Creates a `DirectorySource` resolving paths relative to `directory`.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        let directory = directory.into();
+        Self { directory }
+    }
+}
+
+impl SourceProvider for DirectorySource {
+    fn source(&self, path: &str) -> Result<String, String> {
+        let path = self.directory.join(path);
+        std::fs::read_to_string(&path).map_err(|error| format!("Couldn't read {}: {}", path.display(), error))
+    }
+}
+
+/// A `SourceProvider` with sources kept in memory.
+#[derive(Default)]
+pub struct MemorySource {
+    sources : HashMap<String, String>
+}
+
+impl MemorySource {
+    /// Creates an empty `MemorySource`.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds the `source` at `path`.
+    pub fn with_source(mut self, path: &str, source: &str) -> Self {
+        self.insert(path, source);
+        self
+    }
+
+    /// Adds or replaces the `source` at `path`.
+    pub fn insert(&mut self, path: &str, source: &str) {
+        self.sources.insert(path.to_string(), source.to_string());
+    }
+}
+
+impl SourceProvider for MemorySource {
+    fn source(&self, path: &str) -> Result<String, String> {
+        self.sources.get(path).cloned().ok_or_else(|| format!("Source {} not found", path))
+    }
+}
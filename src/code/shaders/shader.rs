@@ -1,38 +1,155 @@
-use crate::{Context, GLContext};
+use crate::{Context, GLContext, ShaderStage, ShaderSource, ShaderError, Diagnostic};
+#[cfg(feature = "naga")]
+use crate::GlslDialect;
+#[cfg(feature = "naga")]
+use crate::code::shaders::naga_bridge;
+#[cfg(feature = "wgsl")]
+use crate::WgslBindings;
+use std::borrow::Cow;
+#[cfg(feature = "naga")]
+use std::ffi::CString;
 
 type ShaderResource = u32;
 
+/// `GL_SHADER_BINARY_FORMAT_SPIR_V`, which the `gl` bindings don't define.
+#[cfg(feature = "naga")]
+const SHADER_BINARY_FORMAT_SPIR_V : u32 = 0x9551;
+
+/// `glSpecializeShader`, which the `gl` bindings don't load.
+#[cfg(feature = "naga")]
+type SpecializeShader = extern "system" fn(u32, *const i8, u32, *const u32, *const u32);
+
 /// A shader representation.
 pub struct Shader {
-    resource : ShaderResource,
-    _gl: GLContext
+    resource    : ShaderResource,
+    stage       : ShaderStage,
+    diagnostics : Vec<Diagnostic>,
+    _gl         : GLContext
 }
 
 impl Shader {
-    /// Creates a new `Shader`.
-    pub fn new(context: &Context, shader_type:u32, source:&str) -> Result<Self, String> {
-        let gl       = context.gl_context();
-        let resource = unsafe { gl::CreateShader(shader_type) };
+    /// Creates a new `Shader` from a source code or a `PreprocessedSource`.
+    pub fn new<'a>(context: &Context, shader_type:u32, source: impl Into<ShaderSource<'a>>) -> Result<Self, String> {
+        let stage = ShaderStage::from_internal(shader_type)
+            .ok_or_else(|| format!("Unknown shader type {:#x}", shader_type))?;
+        Ok(Self::compile(context, stage, source)?)
+    }
+
+    /// Compiles a `Shader` of the `stage`, reporting failures as a `ShaderError`.
+    pub fn compile<'a>(context: &Context, stage: ShaderStage, source: impl Into<ShaderSource<'a>>) -> Result<Self, ShaderError> {
+        let shader_source = source.into();
+        let source   = match shader_source {
+            ShaderSource::Versionless(text) => Cow::Owned(context.glsl_dialect().adapt(Some(stage), text)),
+            _ => Cow::Borrowed(shader_source.text())
+        };
+        let _gl      = context.gl_context();
+        let resource = unsafe { gl::CreateShader(stage.internal()) };
+        let mut shader = Self { resource, stage, diagnostics: Vec::new(), _gl };
         unsafe {
             gl::ShaderSource(resource, 1, &(source.as_ptr() as *const i8), &(source.len() as i32));
             gl::CompileShader(resource);
+        }
+        let log = shader.info_log();
+        if !shader.is_compiled() {
+            return Err(ShaderError::new(stage, &log, &shader_source.files(), shader_source.contents()))
+        }
+        shader.diagnostics = Diagnostic::parse_log(stage, &log, &shader_source.files());
+        Ok(shader)
+    }
+
+    /// Validates a GLSL `source` for the `stage` offline with naga, without a `Context`. naga
+    /// only supports vertex, fragment and compute shaders with `#version 440`, `450` or `460`;
+    /// `Versionless` sources are validated as `#version 450`.
+    #[cfg(feature = "naga")]
+    pub fn validate<'a>(stage: ShaderStage, source: impl Into<ShaderSource<'a>>) -> Result<(), ShaderError> {
+        naga_bridge::validate(stage, source.into())
+    }
+
+    /// Creates a `Shader` from the `entry_point` of a SPIR-V module. It's loaded directly if the
+    /// driver supports `ARB_gl_spirv`, otherwise it's cross-compiled to the `Context`'s
+    /// `GlslDialect` with naga.
+    #[cfg(feature = "naga")]
+    pub fn from_spirv(context: &Context, stage: ShaderStage, spirv: &[u8], entry_point: &str) -> Result<Self, String> {
+        if let Some(shader) = Self::load_spirv(context, stage, spirv, entry_point)? {
+            return Ok(shader)
+        }
+        let source = Self::cross_compile(stage, spirv, entry_point, context.glsl_dialect())?;
+        Ok(Self::compile(context, stage, source.as_str())?)
+    }
+
+    /// Translates the `entry_point` of a SPIR-V module to GLSL source for the `dialect` with
+    /// naga, without a `Context`. Resources keep their SPIR-V `binding` as their binding point,
+    /// so the module can only use a single descriptor set.
+    #[cfg(feature = "naga")]
+    pub fn cross_compile(stage: ShaderStage, spirv: &[u8], entry_point: &str, dialect: GlslDialect) -> Result<String, String> {
+        naga_bridge::cross_compile(stage, spirv, entry_point, dialect)
+    }
+
+    /// Creates a `Shader` from the `entry_point` of a WGSL `source`, translated to the `Context`'s
+    /// `GlslDialect`. The returned `WgslBindings` maps the WGSL resources to their binding points.
+    #[cfg(feature = "wgsl")]
+    pub fn from_wgsl(context: &Context, stage: ShaderStage, source: &str, entry_point: &str) -> Result<(Self, WgslBindings), String> {
+        let (source, bindings) = naga_bridge::translate_wgsl(stage, source, entry_point, context.glsl_dialect())?;
+        Ok((Self::compile(context, stage, source.as_str())?, bindings))
+    }
 
-            let mut compile_status = gl::FALSE as i32;
-            gl::GetShaderiv(resource, gl::COMPILE_STATUS, &mut compile_status);
-            if compile_status == gl::FALSE as i32 {
-                let buffer_size = 4096;
-                let mut length = 0;
-                let mut buffer : [u8; 4096] = [0; 4096];
-                gl::GetShaderInfoLog(resource, buffer_size, &mut length, buffer.as_mut_ptr() as *mut i8);
-                let err = String::from_raw_parts(buffer.as_mut_ptr(), length as usize, buffer_size as usize);
-                return Err(err)
-            }
+    /// Loads SPIR-V with `ARB_gl_spirv`, returning `None` if the driver doesn't support it.
+    #[cfg(feature = "naga")]
+    fn load_spirv(context: &Context, stage: ShaderStage, spirv: &[u8], entry_point: &str) -> Result<Option<Self>, String> {
+        let core = match context.glsl_dialect() {
+            GlslDialect::Core(version) => version >= 460,
+            _ => false
+        };
+        if !core && !context.has_extension("GL_ARB_gl_spirv") {
+            return Ok(None)
+        }
+        let specialize_shader = context.get_proc_address("glSpecializeShader");
+        if specialize_shader.is_null() {
+            return Ok(None)
+        }
+        let specialize_shader : SpecializeShader = unsafe { std::mem::transmute(specialize_shader) };
+        let entry_point = CString::new(entry_point)
+            .map_err(|_| format!("Entry point `{}` contains a nul character", entry_point))?;
+        let _gl      = context.gl_context();
+        let resource = unsafe { gl::CreateShader(stage.internal()) };
+        let shader   = Self { resource, stage, diagnostics: Vec::new(), _gl };
+        unsafe {
+            gl::ShaderBinary(1, &resource, SHADER_BINARY_FORMAT_SPIR_V, spirv.as_ptr() as *const _, spirv.len() as i32);
+            specialize_shader(resource, entry_point.as_ptr(), 0, std::ptr::null(), std::ptr::null());
+        }
+        if !shader.is_compiled() {
+            return Err(ShaderError::new(stage, &shader.info_log(), &[], Vec::new()).to_string())
+        }
+        Ok(Some(shader))
+    }
+
+    fn is_compiled(&self) -> bool {
+        let mut compile_status = gl::FALSE as i32;
+        unsafe {
+            gl::GetShaderiv(self.resource(), gl::COMPILE_STATUS, &mut compile_status);
+        }
+        compile_status != gl::FALSE as i32
+    }
+
+    fn info_log(&self) -> String {
+        unsafe {
+            let mut length = 0;
+            gl::GetShaderiv(self.resource(), gl::INFO_LOG_LENGTH, &mut length);
+            let mut log = vec![0u8; length.max(1) as usize];
+            gl::GetShaderInfoLog(self.resource(), log.len() as i32, &mut length, log.as_mut_ptr() as *mut i8);
+            log.truncate(length as usize);
+            String::from_utf8_lossy(&log).into_owned()
         }
-        Ok(Self {resource, _gl: gl })
     }
 
     /// Gets the `ShaderResource`.
     pub fn resource(&self) -> ShaderResource { self.resource }
+
+    /// Gets the `ShaderStage`.
+    pub fn stage(&self) -> ShaderStage { self.stage }
+
+    /// Gets the warnings and notes the driver reported while compiling.
+    pub fn diagnostics(&self) -> &[Diagnostic] { &self.diagnostics }
 }
 
 impl Drop for Shader {
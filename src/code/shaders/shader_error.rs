@@ -0,0 +1,73 @@
+use crate::{Diagnostic, Severity, ShaderStage};
+use std::fmt;
+
+/// A failed `Shader` compilation. It displays each `Diagnostic` with a source snippet and a caret.
+#[derive(Clone,Debug)]
+pub struct ShaderError {
+    stage       : ShaderStage,
+    log         : String,
+    diagnostics : Vec<Diagnostic>,
+    sources     : Vec<String>
+}
+
+impl ShaderError {
+    /// Creates a `ShaderError` from the driver `log`. The `sources` are the texts of the source
+    /// strings the diagnostics refer to.
+    pub fn new(stage: ShaderStage, log: &str, files: &[String], sources: Vec<String>) -> Self {
+        let diagnostics = Diagnostic::parse_log(stage, log, files);
+        let log = log.to_string();
+        Self { stage, log, diagnostics, sources }
+    }
+
+    /// Gets the stage of the shader.
+    pub fn stage(&self) -> ShaderStage { self.stage }
+
+    /// Gets the raw driver log.
+    pub fn log(&self) -> &str { &self.log }
+
+    /// Gets the parsed diagnostics.
+    pub fn diagnostics(&self) -> &[Diagnostic] { &self.diagnostics }
+
+    /// Gets the diagnostics with `Severity::Error`.
+    pub fn errors(&self) -> Vec<&Diagnostic> {
+        self.diagnostics.iter().filter(|diagnostic| diagnostic.severity == Severity::Error).collect()
+    }
+
+    fn snippet(&self, diagnostic: &Diagnostic) -> Option<String> {
+        let line_number = diagnostic.line?;
+        let source = self.sources.get(diagnostic.source.unwrap_or(0))?;
+        let line = source.lines().nth(line_number.checked_sub(1)?)?;
+        let gutter = line_number.to_string().len();
+        let column = diagnostic.column
+            .map(|column| column.saturating_sub(1))
+            .unwrap_or_else(|| line.len() - line.trim_start().len());
+        let indentation : String = line.chars().take(column).map(|c| if c == '\t' { '\t' } else { ' ' }).collect();
+        Some(format!("{:gutter$} |\n{} | {}\n{:gutter$} | {}^", "", line_number, line, "", indentation, gutter = gutter))
+    }
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.diagnostics.is_empty() {
+            return write!(f, "Failed to compile {} shader: {}", self.stage.name(), self.log)
+        }
+        for (index, diagnostic) in self.diagnostics.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", diagnostic)?;
+            if let Some(snippet) = self.snippet(diagnostic) {
+                write!(f, "\n{}", snippet)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+impl From<ShaderError> for String {
+    fn from(error: ShaderError) -> Self {
+        error.to_string()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::PreprocessedSource;
+
+/// The source code of a `Shader`.
+#[derive(Clone,Copy,Debug)]
+pub enum ShaderSource<'a> {
+    /// A complete source.
+    Text(&'a str),
+    /// A source produced by a `Preprocessor`. Compile errors refer to the original files.
+    Preprocessed(&'a PreprocessedSource),
+    /// A source without `#version`, adapted to the `GlslDialect` of the `Context`.
+    Versionless(&'a str)
+}
+
+impl<'a> ShaderSource<'a> {
+    /// Gets the source code. Versionless sources are adapted before being passed to the driver.
+    pub fn text(&self) -> &'a str {
+        match self {
+            ShaderSource::Text(text) | ShaderSource::Versionless(text) => text,
+            ShaderSource::Preprocessed(source) => source.source()
+        }
+    }
+
+    /// Gets the names of the source strings referred to by driver logs. Only preprocessed
+    /// sources have names.
+    pub fn files(&self) -> Vec<String> {
+        match self {
+            ShaderSource::Preprocessed(source) => source.files().to_vec(),
+            _ => Vec::new()
+        }
+    }
+
+    /// Gets the original texts of the source strings referred to by driver logs.
+    pub fn contents(&self) -> Vec<String> {
+        match self {
+            ShaderSource::Preprocessed(source) => source.contents().to_vec(),
+            ShaderSource::Text(text) | ShaderSource::Versionless(text) => vec![text.to_string()]
+        }
+    }
+
+    /// Maps a driver log back to the original files.
+    pub fn map_log(&self, log: &str) -> String {
+        match self {
+            ShaderSource::Text(_) | ShaderSource::Versionless(_) => log.to_string(),
+            ShaderSource::Preprocessed(source) => source.map_log(log)
+        }
+    }
+}
+
+impl<'a> From<&'a str> for ShaderSource<'a> {
+    fn from(text: &'a str) -> Self {
+        ShaderSource::Text(text)
+    }
+}
+
+impl<'a> From<&'a String> for ShaderSource<'a> {
+    fn from(text: &'a String) -> Self {
+        ShaderSource::Text(text)
+    }
+}
+
+impl<'a> From<&'a PreprocessedSource> for ShaderSource<'a> {
+    fn from(source: &'a PreprocessedSource) -> Self {
+        ShaderSource::Preprocessed(source)
+    }
+}
@@ -0,0 +1,55 @@
+/// The pipeline stages a `Shader` can be attached to.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash,PartialOrd,Ord)]
+pub enum ShaderStage {
+    /// Vertex stage.
+    Vertex,
+    /// Tessellation control stage.
+    TessControl,
+    /// Tessellation evaluation stage.
+    TessEvaluation,
+    /// Geometry stage.
+    Geometry,
+    /// Fragment stage.
+    Fragment,
+    /// Compute stage.
+    Compute
+}
+
+impl ShaderStage {
+    /// Creates a `ShaderStage` from the `OpenGL` shader type.
+    pub fn from_internal(shader_type: u32) -> Option<Self> {
+        match shader_type {
+            gl::VERTEX_SHADER          => Some(ShaderStage::Vertex),
+            gl::TESS_CONTROL_SHADER    => Some(ShaderStage::TessControl),
+            gl::TESS_EVALUATION_SHADER => Some(ShaderStage::TessEvaluation),
+            gl::GEOMETRY_SHADER        => Some(ShaderStage::Geometry),
+            gl::FRAGMENT_SHADER        => Some(ShaderStage::Fragment),
+            gl::COMPUTE_SHADER         => Some(ShaderStage::Compute),
+            _                          => None
+        }
+    }
+
+    /// Gets the `OpenGL` shader type.
+    pub fn internal(&self) -> u32 {
+        match self {
+            ShaderStage::Vertex         => gl::VERTEX_SHADER,
+            ShaderStage::TessControl    => gl::TESS_CONTROL_SHADER,
+            ShaderStage::TessEvaluation => gl::TESS_EVALUATION_SHADER,
+            ShaderStage::Geometry       => gl::GEOMETRY_SHADER,
+            ShaderStage::Fragment       => gl::FRAGMENT_SHADER,
+            ShaderStage::Compute        => gl::COMPUTE_SHADER
+        }
+    }
+
+    /// Gets the name of the stage as used in driver logs, e.g. `tessellation control`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShaderStage::Vertex         => "vertex",
+            ShaderStage::TessControl    => "tessellation control",
+            ShaderStage::TessEvaluation => "tessellation evaluation",
+            ShaderStage::Geometry       => "geometry",
+            ShaderStage::Fragment       => "fragment",
+            ShaderStage::Compute        => "compute"
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use crate::code::shaders::shader::Shader;
+use crate::Context;
+use crate::ShaderSource;
+
+/// A tessellation evaluation shader representation.
+#[derive(Shrinkwrap)]
+pub struct TessEvaluationShader {
+    shader : Shader
+}
+
+impl TessEvaluationShader {
+    /// Creates a new `TessEvaluationShader` from a source code or a `PreprocessedSource`.
+    pub fn new<'a>(context:&Context, source: impl Into<ShaderSource<'a>>) -> Result<Self, String> {
+        let shader = Shader::new(context, gl::TESS_EVALUATION_SHADER, source)?;
+        Ok(Self{shader})
+    }
+}
@@ -0,0 +1,67 @@
+/// The kind of a WGSL resource, which decides the kind of `OpenGL` binding point it's mapped to.
+#[derive(Clone,Copy,Debug,PartialEq,Eq,Hash)]
+pub enum BindingKind {
+    /// `var<uniform>`, mapped to a uniform buffer binding.
+    UniformBuffer,
+    /// `var<storage>`, mapped to a shader storage buffer binding.
+    StorageBuffer,
+    /// A sampled texture, mapped to a texture unit.
+    Texture,
+    /// A storage texture, mapped to an image unit.
+    StorageImage,
+    /// A sampler, which shares the texture units of the textures it's sampled with.
+    Sampler
+}
+
+/// A WGSL resource mapped to an `OpenGL` binding point.
+#[derive(Clone,Debug,PartialEq)]
+pub struct WgslBinding {
+    /// Name in the WGSL source.
+    pub name          : String,
+    /// `@group` index.
+    pub group         : u32,
+    /// `@binding` index.
+    pub binding       : u32,
+    /// Kind of resource.
+    pub kind          : BindingKind,
+    /// Binding point, texture unit or image unit. Samplers get the first of their
+    /// `texture_units`, or `None` if they aren't sampled with any texture.
+    pub unit          : Option<usize>,
+    /// Units of the textures a sampler is sampled with, which it must be bound to. It's empty
+    /// for the other kinds.
+    pub texture_units : Vec<usize>,
+    /// Names of the block or samplers in the translated GLSL. A texture has a combined sampler
+    /// for its sampler and one for fetches without a sampler. It's empty for samplers and for
+    /// resources the entry point doesn't use.
+    pub glsl_names    : Vec<String>
+}
+
+/// The `OpenGL` binding points assigned to the resources of a WGSL module.
+///
+/// Each kind of binding point is numbered from `0` in `(group, binding)` order over all the
+/// resources declared in the module, so shaders translated from the same module agree on the
+/// binding points.
+#[derive(Clone,Debug,Default,PartialEq)]
+pub struct WgslBindings {
+    bindings : Vec<WgslBinding>
+}
+
+impl WgslBindings {
+    pub(crate) fn new(bindings: Vec<WgslBinding>) -> Self {
+        Self { bindings }
+    }
+
+    /// Gets all the bindings in `(group, binding)` order.
+    pub fn bindings(&self) -> &[WgslBinding] { &self.bindings }
+
+    /// Gets the binding of the WGSL resource called `name`.
+    pub fn get(&self, name: &str) -> Option<&WgslBinding> {
+        self.bindings.iter().find(|binding| binding.name == name)
+    }
+
+    /// Gets the binding point of the WGSL resource called `name`, to be used with
+    /// `Program::bind_uniform_buffer`, `Program::bind_sampler` and similar.
+    pub fn unit(&self, name: &str) -> Option<usize> {
+        self.get(name).and_then(|binding| binding.unit)
+    }
+}
@@ -6,8 +6,16 @@ pub use context_display::ContextDisplay;
 mod context_builder;
 pub use context_builder::ContextBuilder;
 
+mod memory_barrier;
+pub use memory_barrier::MemoryBarrier;
+
+mod context_info;
+pub use context_info::ContextInfo;
+
 pub mod backend;
 pub(crate) use backend::gl_context::{GLContext, HasGLContext};
+use crate::GlslDialect;
+use std::ffi::CStr;
 
 /// A trait defining the `GPUContext` interface.
 pub trait HasContext: HasGLContext {
@@ -28,6 +36,45 @@ pub trait HasContext: HasGLContext {
 
     /// Gets the `ContextDisplay`'s resolution.
     fn resolution(&self) -> (usize, usize);
+
+    /// Gets the driver vendor, renderer and versions.
+    fn info(&self) -> ContextInfo {
+        ContextInfo::query()
+    }
+
+    /// Checks if the driver exposes the `OpenGL` extension called `name`, e.g. `GL_ARB_gl_spirv`.
+    fn has_extension(&self, name: &str) -> bool {
+        unsafe {
+            let mut count = 0;
+            gl::GetIntegerv(gl::NUM_EXTENSIONS, &mut count);
+            (0..count.max(0) as u32).any(|index| {
+                let extension = gl::GetStringi(gl::EXTENSIONS, index);
+                !extension.is_null() && CStr::from_ptr(extension as *const _).to_bytes() == name.as_bytes()
+            })
+        }
+    }
+
+    /// Gets the `GlslDialect` versionless shader sources are adapted to.
+    fn glsl_dialect(&self) -> GlslDialect {
+        GlslDialect::from_version_string(&self.info().shading_language_version)
+    }
+
+    /// Orders the shader writes issued until now before the `MemoryBarrier`'s kinds of reads
+    /// issued from now on.
+    fn memory_barrier(&self, barrier: MemoryBarrier) {
+        unsafe {
+            gl::MemoryBarrier(barrier.bits());
+        }
+    }
+
+    /// Same as `memory_barrier`, but only orders the accesses of fragment shaders to the same
+    /// framebuffer region. Only `ATOMIC_COUNTER`, `FRAMEBUFFER`, `SHADER_IMAGE_ACCESS`,
+    /// `SHADER_STORAGE`, `TEXTURE_FETCH` and `UNIFORM` are allowed.
+    fn memory_barrier_by_region(&self, barrier: MemoryBarrier) {
+        unsafe {
+            gl::MemoryBarrierByRegion(barrier.bits());
+        }
+    }
 }
 
 /// The `Context` object.
@@ -0,0 +1,36 @@
+use std::ffi::CStr;
+
+/// Driver identification strings of a `Context`.
+#[derive(Clone,Debug,PartialEq,Eq,Hash)]
+pub struct ContextInfo {
+    /// Company responsible for the implementation, e.g. `NVIDIA Corporation`.
+    pub vendor                   : String,
+    /// Name of the renderer, usually the GPU model.
+    pub renderer                 : String,
+    /// `OpenGL` version and driver release.
+    pub version                  : String,
+    /// GLSL version supported by the driver.
+    pub shading_language_version : String
+}
+
+impl ContextInfo {
+    /// Queries the strings of the current `Context`.
+    pub(crate) fn query() -> Self {
+        let vendor                   = Self::string(gl::VENDOR);
+        let renderer                 = Self::string(gl::RENDERER);
+        let version                  = Self::string(gl::VERSION);
+        let shading_language_version = Self::string(gl::SHADING_LANGUAGE_VERSION);
+        Self { vendor, renderer, version, shading_language_version }
+    }
+
+    fn string(name: u32) -> String {
+        unsafe {
+            let string = gl::GetString(name);
+            if string.is_null() {
+                String::new()
+            } else {
+                CStr::from_ptr(string as *const _).to_string_lossy().into_owned()
+            }
+        }
+    }
+}
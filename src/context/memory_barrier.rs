@@ -0,0 +1,62 @@
+use std::ops::{BitOr, BitOrAssign};
+
+/// A set of memory barriers ordering incoherent shader writes, such as storage buffer and image
+/// stores, before the specified kinds of reads. They can be combined with `|`.
+#[derive(Clone,Copy,Debug,PartialEq,Eq)]
+pub struct MemoryBarrier(u32);
+
+impl MemoryBarrier {
+    /// Vertex attributes sourced from buffers.
+    pub const VERTEX_ATTRIB_ARRAY  : Self = Self(gl::VERTEX_ATTRIB_ARRAY_BARRIER_BIT);
+    /// Indices sourced from buffers.
+    pub const ELEMENT_ARRAY        : Self = Self(gl::ELEMENT_ARRAY_BARRIER_BIT);
+    /// Uniform blocks sourced from buffers.
+    pub const UNIFORM              : Self = Self(gl::UNIFORM_BARRIER_BIT);
+    /// Texture fetches through samplers.
+    pub const TEXTURE_FETCH        : Self = Self(gl::TEXTURE_FETCH_BARRIER_BIT);
+    /// Image loads, stores and atomics in shaders.
+    pub const SHADER_IMAGE_ACCESS  : Self = Self(gl::SHADER_IMAGE_ACCESS_BARRIER_BIT);
+    /// Indirect draw and dispatch commands sourced from buffers.
+    pub const COMMAND              : Self = Self(gl::COMMAND_BARRIER_BIT);
+    /// Pixel transfers to and from buffers.
+    pub const PIXEL_BUFFER         : Self = Self(gl::PIXEL_BUFFER_BARRIER_BIT);
+    /// Image data uploads and downloads.
+    pub const TEXTURE_UPDATE       : Self = Self(gl::TEXTURE_UPDATE_BARRIER_BIT);
+    /// Buffer data uploads, downloads, copies and mappings.
+    pub const BUFFER_UPDATE        : Self = Self(gl::BUFFER_UPDATE_BARRIER_BIT);
+    /// Framebuffer reads and writes.
+    pub const FRAMEBUFFER          : Self = Self(gl::FRAMEBUFFER_BARRIER_BIT);
+    /// Transform feedback writes.
+    pub const TRANSFORM_FEEDBACK   : Self = Self(gl::TRANSFORM_FEEDBACK_BARRIER_BIT);
+    /// Atomic counters sourced from buffers.
+    pub const ATOMIC_COUNTER       : Self = Self(gl::ATOMIC_COUNTER_BARRIER_BIT);
+    /// Shader storage buffer accesses.
+    pub const SHADER_STORAGE       : Self = Self(gl::SHADER_STORAGE_BARRIER_BIT);
+    /// Client reads of persistently mapped buffers.
+    pub const CLIENT_MAPPED_BUFFER : Self = Self(gl::CLIENT_MAPPED_BUFFER_BARRIER_BIT);
+    /// Query results written to buffers.
+    pub const QUERY_BUFFER         : Self = Self(gl::QUERY_BUFFER_BARRIER_BIT);
+    /// All of the above.
+    pub const ALL                  : Self = Self(gl::ALL_BARRIER_BITS);
+
+    /// Checks if all the barriers in `other` are also in `self`.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Gets `OpenGL` internal representation.
+    pub fn bits(&self) -> u32 { self.0 }
+}
+
+impl BitOr for MemoryBarrier {
+    type Output = Self;
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for MemoryBarrier {
+    fn bitor_assign(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
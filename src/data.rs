@@ -1,12 +1,14 @@
 mod buffer;
+mod ring_buffer;
 mod image;
 mod vertex_array_object;
 mod renderbuffer;
 mod framebuffer;
 
-pub use buffer::Buffer;
+pub use buffer::{Buffer, BufferRange};
+pub use ring_buffer::RingBuffer;
 pub use image::*;
-pub use vertex_array_object::VertexArrayObject;
+pub use vertex_array_object::*;
 pub use renderbuffer::Renderbuffer;
 pub use framebuffer::Framebuffer;
 
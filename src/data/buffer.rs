@@ -3,6 +3,22 @@ use crate::data::as_u8_slice;
 
 type BufferResource = u32;
 
+/// A range of bytes in a `Buffer`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct BufferRange {
+    /// Offset in bytes from the beginning of the `Buffer`.
+    pub offset : usize,
+    /// Size in bytes.
+    pub size   : usize
+}
+
+impl BufferRange {
+    /// Creates a new `BufferRange`.
+    pub fn new(offset: usize, size: usize) -> Self {
+        Self { offset, size }
+    }
+}
+
 /// A `Buffer` representation.
 pub struct Buffer {
     _gl: GLContext,
@@ -45,6 +61,12 @@ impl Buffer {
         }
     }
 
+    pub(crate) fn bind_to(&self, target: u32) {
+        unsafe {
+            gl::BindBuffer(target, self.resource());
+        }
+    }
+
     /// Gets the size in bytes.
     pub fn size(&self) -> usize {
         self.bind();
@@ -78,6 +100,25 @@ impl Buffer {
         data
     }
 
+    /// Writes `data` at `offset` without waiting for the GPU to finish using the `Buffer`. The
+    /// caller must guarantee that the range isn't in use by any pending command.
+    /// It fails if the range can't be mapped, e.g. because it's out of bounds or the `Buffer` is
+    /// already mapped.
+    pub(crate) fn write_unsynchronized(&self, offset: usize, data: &[u8]) -> Result<(), String> {
+        if data.is_empty() { return Ok(()) }
+        self.bind();
+        unsafe {
+            let access = gl::MAP_WRITE_BIT | gl::MAP_UNSYNCHRONIZED_BIT | gl::MAP_INVALIDATE_RANGE_BIT;
+            let ptr = gl::MapBufferRange(gl::ARRAY_BUFFER, offset as isize, data.len() as isize, access);
+            if ptr.is_null() {
+                return Err(format!("Failed to map {} bytes at offset {} of the Buffer", data.len(), offset))
+            }
+            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr as *mut u8, data.len());
+            gl::UnmapBuffer(gl::ARRAY_BUFFER);
+        }
+        Ok(())
+    }
+
     /// Reallocates the memory with `size`.
     pub fn reallocate(&mut self, size: usize) {
         self.bind();
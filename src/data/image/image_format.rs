@@ -17,6 +17,22 @@ impl ImageFormat {
 
     /// Gets the component's `Type`.
     pub fn component_type(&self) -> &Type { &self.1 }
+
+    /// Checks if the format can be used with `imageLoad` and `imageStore`. Three component
+    /// formats can't and `OpenGL ES` only accepts one component 32 bits and four component
+    /// formats.
+    pub fn is_storage_compatible(&self, es: bool) -> bool {
+        if es {
+            matches!((self.0, self.1), (ColorFormat::RGBA, _) | (ColorFormat::R, Type::F32) | (ColorFormat::R, Type::I32) | (ColorFormat::R, Type::U32))
+        } else {
+            !matches!(self.0, ColorFormat::RGB)
+        }
+    }
+
+    /// Gets the size of a texel in bytes.
+    pub fn texel_size(&self) -> usize {
+        self.0.size() * self.1.size()
+    }
 }
 
 impl ImageFormat {
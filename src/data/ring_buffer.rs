@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+
+use crate::{Context, GLContext};
+use crate::data::{as_u8_slice, Buffer, BufferRange};
+
+/// A fence guarding the bytes used by one finished frame.
+struct FrameFence {
+    sync     : gl::types::GLsync,
+    n_bytes  : usize
+}
+
+impl FrameFence {
+    /// Blocks until the GPU has consumed every command issued before the fence.
+    fn wait(&self) {
+        // One second per try. We keep on trying until the fence is signaled or the wait fails.
+        let timeout = 1_000_000_000;
+        unsafe {
+            while let gl::TIMEOUT_EXPIRED = gl::ClientWaitSync(self.sync, gl::SYNC_FLUSH_COMMANDS_BIT, timeout) {}
+        }
+    }
+}
+
+impl Drop for FrameFence {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteSync(self.sync);
+        }
+    }
+}
+
+/// A `Buffer` used as a ring for streaming transient per-frame data, such as dynamic vertices,
+/// indices and uniforms.
+///
+/// Every frame sub-allocates aligned `BufferRange`s from the ring. When the frame is done,
+/// `finish_frame` fences the bytes it used and they are only reused once the GPU has consumed
+/// them, so writing never stalls on an implicit synchronization.
+pub struct RingBuffer {
+    _gl                : GLContext,
+    buffer             : Buffer,
+    head               : usize,
+    used               : usize,
+    frame_bytes        : usize,
+    fences             : VecDeque<FrameFence>,
+    uniform_alignment  : usize
+}
+
+impl RingBuffer {
+    /// Creates a new `RingBuffer` with `n_bytes` of storage.
+    pub fn new(context:&Context, n_bytes:usize) -> Self {
+        let gl     = context.gl_context();
+        let buffer = Buffer::allocate(context, n_bytes);
+        let uniform_alignment = unsafe {
+            let mut alignment = 0;
+            gl::GetIntegerv(gl::UNIFORM_BUFFER_OFFSET_ALIGNMENT, &mut alignment);
+            alignment.max(1) as usize
+        };
+        let head        = 0;
+        let used        = 0;
+        let frame_bytes = 0;
+        let fences      = VecDeque::new();
+        Self { _gl: gl, buffer, head, used, frame_bytes, fences, uniform_alignment }
+    }
+
+    /// Gets the underlying `Buffer`.
+    pub fn buffer(&self) -> &Buffer { &self.buffer }
+
+    /// Gets the size of the ring in bytes.
+    pub fn size(&self) -> usize { self.buffer.size() }
+
+    /// Gets the offset alignment required for binding uniform ranges.
+    pub fn uniform_alignment(&self) -> usize { self.uniform_alignment }
+
+    /// Sub-allocates `n_bytes` aligned to `alignment` bytes. It waits for the oldest in-flight
+    /// frames if the ring is full and fails if the current frame alone doesn't fit in the ring.
+    pub fn allocate(&mut self, n_bytes:usize, alignment:usize) -> Result<BufferRange, String> {
+        let capacity  = self.size();
+        let alignment = alignment.max(1);
+        let aligned   = self.head.div_ceil(alignment) * alignment;
+        let offset    = if aligned + n_bytes > capacity { 0 } else { aligned };
+        // Bytes consumed from the ring: padding, the wasted tail when wrapping and the data.
+        let consumed  = if offset == 0 && self.head != 0 { capacity - self.head } else { offset - self.head } + n_bytes;
+
+        if self.frame_bytes + consumed > capacity {
+            return Err(format!("RingBuffer of {} bytes can't fit {} bytes in a single frame", capacity, self.frame_bytes + consumed))
+        }
+        while self.used + consumed > capacity {
+            match self.fences.pop_front() {
+                Some(fence) => {
+                    fence.wait();
+                    self.used -= fence.n_bytes;
+                },
+                None => break
+            }
+        }
+
+        self.head         = offset + n_bytes;
+        self.used        += consumed;
+        self.frame_bytes += consumed;
+        Ok(BufferRange::new(offset, n_bytes))
+    }
+
+    /// Sub-allocates and writes `data` aligned to `alignment` bytes.
+    pub fn push<T>(&mut self, data:&[T], alignment:usize) -> Result<BufferRange, String> {
+        let slice = as_u8_slice(data);
+        let range = self.allocate(slice.len(), alignment)?;
+        self.buffer.write_unsynchronized(range.offset, slice)?;
+        Ok(range)
+    }
+
+    /// Sub-allocates and writes `data` aligned for binding it as a uniform range.
+    pub fn push_uniform<T>(&mut self, data:&T) -> Result<BufferRange, String> {
+        let alignment = self.uniform_alignment();
+        self.push(std::slice::from_ref(data), alignment)
+    }
+
+    /// Fences all the ranges allocated since the last call. They will only be reused once the
+    /// GPU has finished the commands issued until now.
+    pub fn finish_frame(&mut self) {
+        if self.frame_bytes == 0 { return }
+        let sync    = unsafe { gl::FenceSync(gl::SYNC_GPU_COMMANDS_COMPLETE, 0) };
+        let n_bytes = self.frame_bytes;
+        self.fences.push_back(FrameFence { sync, n_bytes });
+        self.frame_bytes = 0;
+    }
+}
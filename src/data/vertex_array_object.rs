@@ -1,4 +1,12 @@
-use crate::data::Buffer;
+mod vertex_attribute;
+mod vertex;
+mod index_buffer;
+
+pub use vertex_attribute::*;
+pub use vertex::*;
+pub use index_buffer::*;
+
+use crate::data::{Buffer, BufferRange};
 use crate::{Context, GLContext};
 
 type VertexArrayObjectResource = u32;
@@ -7,8 +15,12 @@ type VertexArrayObjectResource = u32;
 /// `VertexArrayObject` representation.
 pub struct VertexArrayObject {
     _gl: GLContext,
-    resource : VertexArrayObjectResource,
-    vertices : usize
+    resource   : VertexArrayObjectResource,
+    vertices   : usize,
+    index_type : Option<IndexType>,
+    n_indices  : usize,
+    primitive_restart : Option<PrimitiveRestart>,
+    attributes : Vec<(usize, VertexAttribute)>
 }
 
 impl VertexArrayObject {
@@ -20,8 +32,12 @@ impl VertexArrayObject {
             gl::CreateVertexArrays(1, &mut resource);
             resource
         };
-        let vertices = 0;
-        Self { _gl: gl, resource, vertices }
+        let vertices   = 0;
+        let index_type = None;
+        let n_indices  = 0;
+        let primitive_restart = None;
+        let attributes = Vec::new();
+        Self { _gl: gl, resource, vertices, index_type, n_indices, primitive_restart, attributes }
     }
 
     pub(crate) fn resource(&self) -> VertexArrayObjectResource {
@@ -34,22 +50,121 @@ impl VertexArrayObject {
         }
     }
 
-    // TODO: Allow to define the VertexBuffer component type. (It's hardcoded to gl::FALSE)
-    /// Sets a `Buffer` as a vertices sources, where each vertex has `n_elements`
-    pub fn set_vertex_buffer(&mut self, buffer : &Buffer, attribute_index: usize, n_elements: usize) {
+    /// Sets a `Buffer` as a vertices source, where each vertex has `n_elements` floats. It fails
+    /// if `n_elements` isn't between 1 and 4.
+    pub fn set_vertex_buffer(&mut self, buffer : &Buffer, attribute_index: usize, n_elements: usize) -> Result<(), String> {
+        let attribute = VertexAttribute::new(n_elements, AttributeType::F32);
+        self.set_vertex_attribute(buffer, attribute_index, &attribute)
+    }
+
+    /// Sets a `BufferRange` of a `Buffer` as a vertices source, where each vertex has `n_elements`.
+    pub fn set_vertex_buffer_range(&mut self, buffer : &Buffer, range: BufferRange, attribute_index: usize, n_elements: usize) -> Result<(), String> {
+        let attribute = VertexAttribute::new(n_elements, AttributeType::F32).with_offset(range.offset);
+        self.set_vertex_attribute(buffer, attribute_index, &attribute)
+    }
+
+    /// Sets a `Buffer` as the source of the attribute at `attribute_index`, laid out as described
+    /// by `attribute`. Interleaved vertices are described by one `VertexAttribute` per field, all
+    /// sharing the same `Buffer` and stride.
+    pub fn set_vertex_attribute(&mut self, buffer : &Buffer, attribute_index: usize, attribute: &VertexAttribute) -> Result<(), String> {
+        attribute.validate()?;
         self.bind();
         buffer.bind();
         unsafe {
-            gl::EnableVertexAttribArray(attribute_index as u32);
-            gl::VertexAttribPointer(attribute_index as u32, n_elements as i32, gl::FLOAT, gl::FALSE, 0, std::ptr::null());
+            let index        = attribute_index as u32;
+            let n_components = attribute.n_components() as i32;
+            let type_        = attribute.type_().format();
+            let stride       = attribute.stride() as i32;
+            let offset       = attribute.offset() as *const std::ffi::c_void;
+            gl::EnableVertexAttribArray(index);
+            match attribute.kind() {
+                AttributeKind::Integer    => gl::VertexAttribIPointer(index, n_components, type_, stride, offset),
+                AttributeKind::Normalized => gl::VertexAttribPointer(index, n_components, type_, gl::TRUE, stride, offset),
+                AttributeKind::Float      => gl::VertexAttribPointer(index, n_components, type_, gl::FALSE, stride, offset)
+            }
+            gl::VertexAttribDivisor(index, attribute.divisor() as u32);
         }
+        self.attributes.retain(|(index, _)| *index != attribute_index);
+        self.attributes.push((attribute_index, *attribute));
+        Ok(())
+    }
+
+    /// Gets the `VertexAttribute` at `attribute_index`, if it was set.
+    pub fn attribute(&self, attribute_index: usize) -> Option<VertexAttribute> {
+        self.attributes.iter()
+            .find(|(index, _)| *index == attribute_index)
+            .map(|(_, attribute)| *attribute)
     }
 
-    pub fn set_index_buffer(&mut self, buffer : &Buffer) {
+    /// Sets the instance `divisor` of the attribute at `attribute_index`. `0` advances the
+    /// attribute once per vertex and `n` advances it once every `n` instances.
+    pub fn set_attribute_divisor(&mut self, attribute_index: usize, divisor: usize) {
+        self.bind();
+        unsafe {
+            gl::VertexAttribDivisor(attribute_index as u32, divisor as u32);
+        }
+        for (index, attribute) in self.attributes.iter_mut() {
+            if *index == attribute_index {
+                *attribute = attribute.with_divisor(divisor);
+            }
+        }
+    }
+
+    /// Sets a `Buffer` of `V`s as the source of all the attributes described by `V`.
+    pub fn set_vertex_buffer_of<V: Vertex>(&mut self, buffer : &Buffer) -> Result<(), String> {
+        for (attribute_index, attribute) in V::attributes() {
+            self.set_vertex_attribute(buffer, attribute_index, &attribute)?;
+        }
+        Ok(())
+    }
+
+    /// Sets a `Buffer` of `index_type` indices as the index source. The number of indices is taken
+    /// from the current size of the `Buffer`, so it must be set again if the `Buffer` is
+    /// reallocated.
+    pub fn set_index_buffer(&mut self, buffer : &Buffer, index_type: IndexType) {
         self.bind();
         unsafe {
             gl::BindBuffer(gl::ELEMENT_ARRAY_BUFFER, buffer.resource());
         }
+        self.index_type = Some(index_type);
+        self.n_indices  = buffer.size() / index_type.size();
+    }
+
+    /// Gets the `IndexType` of the index `Buffer`, if any.
+    pub fn index_type(&self) -> Option<IndexType> { self.index_type }
+
+    /// Gets the number of indices in the index `Buffer`.
+    pub fn n_indices(&self) -> usize { self.n_indices }
+
+    /// Gets the `IndexRange` covering all the indices.
+    pub fn index_range(&self) -> IndexRange { IndexRange::new(0, self.n_indices) }
+
+    /// Sets the `PrimitiveRestart` used by indexed raster calls. `None` disables it.
+    pub fn set_primitive_restart(&mut self, primitive_restart: Option<PrimitiveRestart>) {
+        self.primitive_restart = primitive_restart;
+    }
+
+    /// Gets the `PrimitiveRestart` configuration.
+    pub fn primitive_restart(&self) -> Option<PrimitiveRestart> { self.primitive_restart }
+
+    pub(crate) fn bind_primitive_restart(&self) {
+        unsafe {
+            match self.primitive_restart {
+                Some(PrimitiveRestart::Fixed) => {
+                    gl::Disable(gl::PRIMITIVE_RESTART);
+                    gl::Enable(gl::PRIMITIVE_RESTART_FIXED_INDEX);
+                },
+                Some(PrimitiveRestart::Index(index)) => {
+                    gl::Disable(gl::PRIMITIVE_RESTART_FIXED_INDEX);
+                    gl::Enable(gl::PRIMITIVE_RESTART);
+                    gl::PrimitiveRestartIndex(index);
+                },
+                None => {
+                    gl::Disable(gl::PRIMITIVE_RESTART_FIXED_INDEX);
+                    gl::Disable(gl::PRIMITIVE_RESTART);
+                }
+            }
+        }
     }
 }
 
@@ -0,0 +1,65 @@
+/// Types of the indices in an index `Buffer`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum IndexType {
+    /// 8bits unsigned integer indices.
+    U8,
+    /// 16bits unsigned integer indices.
+    U16,
+    /// 32bits unsigned integer indices.
+    U32
+}
+
+impl IndexType {
+    /// Gets the size in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            IndexType::U8  => 1,
+            IndexType::U16 => 2,
+            IndexType::U32 => 4
+        }
+    }
+
+    /// Gets `OpenGL` internal representation.
+    pub fn format(&self) -> u32 {
+        match self {
+            IndexType::U8  => gl::UNSIGNED_BYTE,
+            IndexType::U16 => gl::UNSIGNED_SHORT,
+            IndexType::U32 => gl::UNSIGNED_INT
+        }
+    }
+}
+
+/// A sub-range of the indices in an index `Buffer`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct IndexRange {
+    /// The first index.
+    pub first       : usize,
+    /// The number of indices.
+    pub count       : usize,
+    /// A value added to every index before fetching the vertex.
+    pub base_vertex : usize
+}
+
+impl IndexRange {
+    /// Creates a new `IndexRange` with `count` indices starting at `first`.
+    pub fn new(first: usize, count: usize) -> Self {
+        let base_vertex = 0;
+        Self { first, count, base_vertex }
+    }
+
+    /// Sets the value added to every index before fetching the vertex.
+    pub fn with_base_vertex(mut self, base_vertex: usize) -> Self {
+        self.base_vertex = base_vertex;
+        self
+    }
+}
+
+/// Primitive restart configuration. A restart index in the index `Buffer` ends the current
+/// primitive and starts a new one, so strips and loops can be drawn in a single call.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum PrimitiveRestart {
+    /// Restarts at the maximum value of the `IndexType`.
+    Fixed,
+    /// Restarts at the specified index.
+    Index(u32)
+}
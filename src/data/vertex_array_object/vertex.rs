@@ -0,0 +1,48 @@
+use crate::{AttributeType, VertexAttribute};
+
+/// A vertex type whose fields can be read as vertex attributes from a `Buffer`.
+///
+/// It's usually derived for `#[repr(C)]` structs with `#[derive(Vertex)]`, which requires the
+/// `derive` feature.
+pub trait Vertex {
+    /// Gets the attribute locations and their layouts.
+    fn attributes() -> Vec<(usize, VertexAttribute)>;
+}
+
+/// A type that can be a field of a `Vertex`.
+pub trait VertexComponent {
+    /// Gets the tightly packed `VertexAttribute` of this type.
+    fn attribute() -> VertexAttribute;
+}
+
+macro_rules! vertex_component {
+    ($($ty:ty => $type_:expr),*) => {
+        $(
+            impl VertexComponent for $ty {
+                fn attribute() -> VertexAttribute { VertexAttribute::new(1, $type_) }
+            }
+            impl VertexComponent for [$ty; 1] {
+                fn attribute() -> VertexAttribute { VertexAttribute::new(1, $type_) }
+            }
+            impl VertexComponent for [$ty; 2] {
+                fn attribute() -> VertexAttribute { VertexAttribute::new(2, $type_) }
+            }
+            impl VertexComponent for [$ty; 3] {
+                fn attribute() -> VertexAttribute { VertexAttribute::new(3, $type_) }
+            }
+            impl VertexComponent for [$ty; 4] {
+                fn attribute() -> VertexAttribute { VertexAttribute::new(4, $type_) }
+            }
+        )*
+    }
+}
+
+vertex_component! {
+    u8  => AttributeType::U8,
+    u16 => AttributeType::U16,
+    u32 => AttributeType::U32,
+    i8  => AttributeType::I8,
+    i16 => AttributeType::I16,
+    i32 => AttributeType::I32,
+    f32 => AttributeType::F32
+}
@@ -0,0 +1,176 @@
+use crate::Type;
+
+/// Component types of a `VertexAttribute`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum AttributeType {
+    /// 8bits unsigned integer.
+    U8,
+    /// 16bits unsigned integer.
+    U16,
+    /// 32bits unsigned integer.
+    U32,
+    /// 8bits signed integer.
+    I8,
+    /// 16bits signed integer.
+    I16,
+    /// 32bits signed integer.
+    I32,
+    /// 16bits float.
+    F16,
+    /// 32bits float.
+    F32,
+    /// Four unsigned components packed in 32bits as 10, 10, 10 and 2 bits.
+    U10_10_10_2,
+    /// Four signed components packed in 32bits as 10, 10, 10 and 2 bits.
+    I10_10_10_2
+}
+
+impl AttributeType {
+    /// Checks if the components are packed in a single 32bits value.
+    pub fn is_packed(&self) -> bool {
+        matches!(self, AttributeType::U10_10_10_2 | AttributeType::I10_10_10_2)
+    }
+
+    /// Checks if it's a float type.
+    pub fn is_float(&self) -> bool {
+        matches!(self, AttributeType::F16 | AttributeType::F32)
+    }
+
+    /// Gets the size in bytes of `n_components`.
+    pub fn size(&self, n_components: usize) -> usize {
+        match self {
+            AttributeType::U8  | AttributeType::I8  => n_components,
+            AttributeType::U16 | AttributeType::I16 | AttributeType::F16 => n_components * 2,
+            AttributeType::U32 | AttributeType::I32 | AttributeType::F32 => n_components * 4,
+            AttributeType::U10_10_10_2 | AttributeType::I10_10_10_2 => 4
+        }
+    }
+
+    /// Gets `OpenGL` internal representation.
+    pub fn format(&self) -> u32 {
+        match self {
+            AttributeType::U8          => gl::UNSIGNED_BYTE,
+            AttributeType::U16         => gl::UNSIGNED_SHORT,
+            AttributeType::U32         => gl::UNSIGNED_INT,
+            AttributeType::I8          => gl::BYTE,
+            AttributeType::I16         => gl::SHORT,
+            AttributeType::I32         => gl::INT,
+            AttributeType::F16         => gl::HALF_FLOAT,
+            AttributeType::F32         => gl::FLOAT,
+            AttributeType::U10_10_10_2 => gl::UNSIGNED_INT_2_10_10_10_REV,
+            AttributeType::I10_10_10_2 => gl::INT_2_10_10_10_REV
+        }
+    }
+}
+
+impl From<Type> for AttributeType {
+    fn from(type_: Type) -> Self {
+        match type_ {
+            Type::U8  => AttributeType::U8,
+            Type::U16 => AttributeType::U16,
+            Type::U32 => AttributeType::U32,
+            Type::I8  => AttributeType::I8,
+            Type::I16 => AttributeType::I16,
+            Type::I32 => AttributeType::I32,
+            Type::F16 => AttributeType::F16,
+            Type::F32 => AttributeType::F32
+        }
+    }
+}
+
+/// How the shader sees the components of a `VertexAttribute`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub enum AttributeKind {
+    /// Components are converted to float as they are.
+    Float,
+    /// Integer components are normalized to [0, 1] if unsigned or [-1, 1] if signed.
+    Normalized,
+    /// Integer components are kept as integers (`int`, `ivec*`, `uint` and `uvec*` inputs).
+    Integer
+}
+
+/// Description of how a vertex attribute is laid out in a `Buffer`.
+#[derive(Clone,Copy,Debug,PartialEq)]
+pub struct VertexAttribute {
+    n_components : usize,
+    type_        : AttributeType,
+    kind         : AttributeKind,
+    stride       : usize,
+    offset       : usize,
+    divisor      : usize
+}
+
+impl VertexAttribute {
+    /// Creates a new `VertexAttribute` with `n_components` of `type_`, converted to float, tightly
+    /// packed, starting at the beginning of the `Buffer` and advancing once per vertex.
+    pub fn new(n_components: usize, type_: AttributeType) -> Self {
+        let kind    = AttributeKind::Float;
+        let stride  = 0;
+        let offset  = 0;
+        let divisor = 0;
+        Self { n_components, type_, kind, stride, offset, divisor }
+    }
+
+    /// Sets how the shader sees the components.
+    pub fn with_kind(mut self, kind: AttributeKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the distance in bytes between two consecutive vertices. `0` means tightly packed.
+    pub fn with_stride(mut self, stride: usize) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    /// Sets the offset in bytes of the first component from the beginning of the `Buffer`.
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the instance divisor. `0` advances the attribute once per vertex and `n` advances it
+    /// once every `n` instances.
+    pub fn with_divisor(mut self, divisor: usize) -> Self {
+        self.divisor = divisor;
+        self
+    }
+
+    /// Gets the number of components.
+    pub fn n_components(&self) -> usize { self.n_components }
+
+    /// Gets the components' `AttributeType`.
+    pub fn type_(&self) -> AttributeType { self.type_ }
+
+    /// Gets the `AttributeKind`.
+    pub fn kind(&self) -> AttributeKind { self.kind }
+
+    /// Gets the stride in bytes.
+    pub fn stride(&self) -> usize { self.stride }
+
+    /// Gets the offset in bytes.
+    pub fn offset(&self) -> usize { self.offset }
+
+    /// Gets the instance divisor.
+    pub fn divisor(&self) -> usize { self.divisor }
+
+    /// Gets the size in bytes of a single attribute.
+    pub fn size(&self) -> usize { self.type_.size(self.n_components) }
+
+    /// Checks if the combination of components, type and kind is valid.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.n_components == 0 || self.n_components > 4 {
+            return Err(format!("A VertexAttribute must have 1 to 4 components, but it has {}", self.n_components))
+        }
+        if self.type_.is_packed() && self.n_components != 4 {
+            return Err(format!("Packed {:?} requires 4 components, but it has {}", self.type_, self.n_components))
+        }
+        match self.kind {
+            AttributeKind::Integer if self.type_.is_float() || self.type_.is_packed() =>
+                Err(format!("{:?} can't be used as an integer attribute", self.type_)),
+            AttributeKind::Normalized if self.type_.is_float() =>
+                Err(format!("{:?} can't be normalized", self.type_)),
+            _ => Ok(())
+        }
+    }
+}
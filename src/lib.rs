@@ -28,3 +28,6 @@ pub use data::*;
 pub use code::*;
 pub use context::*;
 pub use window::*;
+
+#[cfg(feature = "derive")]
+pub use gpu_derive::Vertex;
@@ -27,4 +27,33 @@ mod clear_program {
 
         context.swap_buffers().expect("Couldn't swap buffers");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn clear_scissor() {
+        use gpu::{Image2D, ImageFormat, ColorFormat, Type, Scissor};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let format = ImageFormat::new(ColorFormat::R, Type::F32);
+        let color = Image2D::allocate(&context, (4, 1), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let mut clear_program = ClearProgram::new(&context);
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+
+        clear_program.set_scissor(Some(Scissor::new((1, 0), (2, 1))));
+        clear_program.set_color((1.0, 0.0, 0.0, 0.0));
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![0.0, 1.0, 1.0, 0.0]);
+
+        clear_program.set_scissor(None);
+        clear_program.set_color((2.0, 0.0, 0.0, 0.0));
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![2.0; 4]);
+    }
+}
@@ -0,0 +1,136 @@
+mod utils;
+
+#[cfg(test)]
+mod compute_program {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::ComputeShader;
+    use gpu::ComputeProgram;
+
+
+    #[test]
+    fn local_size() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let shader = ComputeShader::new(&context, r#"#version 430
+            layout(local_size_x = 8, local_size_y = 4, local_size_z = 2) in;
+
+            void main() {}
+        "#).unwrap();
+
+        let program = ComputeProgram::new(&context, &shader).unwrap();
+        assert_eq!(program.local_size(), (8, 4, 2));
+
+        program.compute_invocations((100, 3, 1)).unwrap();
+        assert!(program.compute_invocations((usize::max_value(), 1, 1)).is_err());
+    }
+
+    #[test]
+    fn storage_image() {
+        use gpu::{Image2D, ImageFormat, ColorFormat, Type, StorageImageBinding, ImageAccess, MemoryBarrier};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let shader = ComputeShader::new(&context, r#"#version 430
+            layout(local_size_x = 1, local_size_y = 1) in;
+            layout(rgba32f, binding = 2) writeonly uniform image2D image;
+
+            void main() {
+                imageStore(image, ivec2(gl_GlobalInvocationID.xy), vec4(1.0, 2.0, 3.0, 4.0));
+            }
+        "#).unwrap();
+
+        let program = ComputeProgram::new(&context, &shader).unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let image = Image2D::allocate(&context, (4, 4), &format);
+        let binding = StorageImageBinding::new().with_access(ImageAccess::WriteOnly);
+        program.bind_storage_image(&image, 2, &binding).unwrap();
+        program.compute((4, 4, 1));
+        context.memory_barrier(MemoryBarrier::TEXTURE_UPDATE);
+
+        let data : Vec<f32> = image.data();
+        let expected_data : Vec<f32> = [1.0, 2.0, 3.0, 4.0].iter().cycle().take(4 * 4 * 4).cloned().collect();
+        assert_eq!(data, expected_data);
+
+        let rgb = Image2D::allocate(&context, (4, 4), &ImageFormat::new(ColorFormat::RGB, Type::F32));
+        assert!(program.bind_storage_image(&rgb, 2, &binding).is_err());
+        let r8 = binding.with_format(Some(ImageFormat::new(ColorFormat::R, Type::U8)));
+        assert!(program.bind_storage_image(&image, 2, &r8).is_err());
+        assert!(program.bind_image_2d(&rgb, 2).is_err());
+
+        assert!(ImageFormat::new(ColorFormat::RG, Type::F32).is_storage_compatible(false));
+        assert!(!ImageFormat::new(ColorFormat::RG, Type::F32).is_storage_compatible(true));
+        assert!(!ImageFormat::new(ColorFormat::R, Type::U8).is_storage_compatible(true));
+        assert!(ImageFormat::new(ColorFormat::R, Type::U32).is_storage_compatible(true));
+        assert!(ImageFormat::new(ColorFormat::RGBA, Type::U8).is_storage_compatible(true));
+        assert!(!ImageFormat::new(ColorFormat::RGB, Type::F32).is_storage_compatible(false));
+    }
+
+    #[test]
+    fn memory_barrier() {
+        use gpu::{Image2D, ImageFormat, ColorFormat, Type, StorageImageBinding, ImageAccess, MemoryBarrier};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let write_shader = ComputeShader::new(&context, r#"#version 430
+            layout(local_size_x = 1, local_size_y = 1) in;
+            layout(rgba32f, binding = 0) writeonly uniform image2D image;
+
+            void main() {
+                ivec2 position = ivec2(gl_GlobalInvocationID.xy);
+                imageStore(image, position, vec4(position, 1.0, 1.0));
+            }
+        "#).unwrap();
+
+        // Reads a texel written by another invocation of the previous dispatch.
+        let read_shader = ComputeShader::new(&context, r#"#version 430
+            layout(local_size_x = 1, local_size_y = 1) in;
+            layout(rgba32f, binding = 0) readonly uniform image2D source;
+            layout(rgba32f, binding = 1) writeonly uniform image2D destination;
+
+            void main() {
+                ivec2 position = ivec2(gl_GlobalInvocationID.xy);
+                imageStore(destination, position, imageLoad(source, ivec2(3, 3) - position) * 2.0);
+            }
+        "#).unwrap();
+
+        let write_program = ComputeProgram::new(&context, &write_shader).unwrap();
+        let read_program = ComputeProgram::new(&context, &read_shader).unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let source = Image2D::allocate(&context, (4, 4), &format);
+        let destination = Image2D::allocate(&context, (4, 4), &format);
+        let write_only = StorageImageBinding::new().with_access(ImageAccess::WriteOnly);
+        let read_only = StorageImageBinding::new().with_access(ImageAccess::ReadOnly);
+
+        write_program.bind_storage_image(&source, 0, &write_only).unwrap();
+        write_program.compute((4, 4, 1));
+        context.memory_barrier(MemoryBarrier::SHADER_IMAGE_ACCESS);
+
+        read_program.bind_storage_image(&source, 0, &read_only).unwrap();
+        read_program.bind_storage_image(&destination, 1, &write_only).unwrap();
+        read_program.compute((4, 4, 1));
+        let barrier = MemoryBarrier::SHADER_IMAGE_ACCESS | MemoryBarrier::TEXTURE_UPDATE;
+        assert!(barrier.contains(MemoryBarrier::TEXTURE_UPDATE));
+        assert!(!barrier.contains(MemoryBarrier::SHADER_STORAGE));
+        context.memory_barrier(barrier);
+
+        let data : Vec<f32> = destination.data();
+        let expected_data : Vec<f32> = (0..4)
+            .flat_map(|y| (0..4).map(move |x| (x, y)))
+            .flat_map(|(x, y)| vec![2.0 * (3 - x) as f32, 2.0 * (3 - y) as f32, 2.0, 2.0])
+            .collect();
+        assert_eq!(data, expected_data);
+    }
+}
@@ -0,0 +1,61 @@
+mod utils;
+
+#[cfg(test)]
+mod diagnostic {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{Diagnostic, Severity, Shader, ShaderError, ShaderStage};
+
+
+    #[test]
+    fn parse_vendor_logs() {
+        let files = vec!["main.frag".to_string(), "lighting.glsl".to_string()];
+
+        let mesa = Diagnostic::parse_log(ShaderStage::Fragment, "0:12(5): error: `color' undeclared\n", &files);
+        assert_eq!(mesa.len(), 1);
+        assert_eq!(mesa[0].file.as_deref(), Some("main.frag"));
+        assert_eq!((mesa[0].line, mesa[0].column), (Some(12), Some(5)));
+        assert_eq!(mesa[0].severity, Severity::Error);
+        assert_eq!(mesa[0].message, "`color' undeclared");
+
+        let nvidia = Diagnostic::parse_log(ShaderStage::Vertex, "1(3) : warning C7533: global variable gl_FragColor is deprecated", &files);
+        assert_eq!(nvidia[0].file.as_deref(), Some("lighting.glsl"));
+        assert_eq!((nvidia[0].line, nvidia[0].column), (Some(3), None));
+        assert_eq!(nvidia[0].severity, Severity::Warning);
+        assert_eq!(nvidia[0].message, "global variable gl_FragColor is deprecated");
+
+        let amd = Diagnostic::parse_log(ShaderStage::Compute, "ERROR: 0:7: 'x' : undeclared identifier\nERROR: 1 compilation errors.  No code generated.", &[]);
+        assert_eq!(amd.len(), 2);
+        assert_eq!((amd[0].source, amd[0].line), (Some(0), Some(7)));
+        assert_eq!(amd[0].message, "'x' : undeclared identifier");
+        assert_eq!(amd[1].line, None);
+        assert_eq!(amd[1].severity, Severity::Error);
+    }
+
+    #[test]
+    fn display_with_snippet() {
+        let source = "void main() {\n    color = vec4(1.0);\n}\n".to_string();
+        let error = ShaderError::new(ShaderStage::Fragment, "0:2(5): error: `color' undeclared", &[], vec![source]);
+        assert_eq!(error.errors().len(), 1);
+        assert_eq!(error.to_string(), "fragment shader:2:5: error: `color' undeclared\n  |\n2 |     color = vec4(1.0);\n  |     ^");
+    }
+
+    #[test]
+    fn compile_error() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let error = Shader::compile(&context, ShaderStage::Fragment, r#"#version 300 es
+            precision highp float;
+            void main() {
+                color = vec4(1.0);
+            }
+        "#).err().unwrap();
+        assert_eq!(error.stage(), ShaderStage::Fragment);
+        assert!(!error.errors().is_empty());
+        assert_eq!(error.errors()[0].line, Some(4));
+    }
+}
@@ -0,0 +1,122 @@
+mod utils;
+
+#[cfg(test)]
+mod glsl_dialect {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{GlslDialect, ShaderStage, ShaderSource, Preprocessor, MemorySource};
+    use gpu::{VertexShader, FragmentShader, RasterProgram};
+
+
+    #[test]
+    fn parse_and_adapt() {
+        assert_eq!(GlslDialect::from_version_string("4.60 NVIDIA"), GlslDialect::Core(460));
+        assert_eq!(GlslDialect::from_version_string("OpenGL ES GLSL ES 3.20"), GlslDialect::Es(320));
+        assert_eq!(GlslDialect::from_version_string("WebGL GLSL ES 3.00 (OpenGL ES GLSL ES 3.0 Chromium)"), GlslDialect::WebGl2);
+
+        let source = "varying vec2 uv;\nuniform sampler2D image;\nvoid main() { gl_FragColor = texture2D(image, uv); }\n";
+
+        let core = GlslDialect::Core(330).adapt(Some(ShaderStage::Fragment), source);
+        assert_eq!(core, "#version 330 core\n\
+                          layout(location = 0) out vec4 gpu_FragColor;\n\
+                          #line 1 0\n\
+                          in vec2 uv;\n\
+                          uniform sampler2D image;\n\
+                          void main() { gpu_FragColor = texture(image, uv); }\n");
+
+        let core = GlslDialect::Core(150).adapt(Some(ShaderStage::Fragment), source);
+        assert!(core.starts_with("#version 150 core\nout vec4 gpu_FragColor;\n#line 1 0\nin vec2 uv;\n"));
+
+        let legacy = GlslDialect::Core(120).adapt(Some(ShaderStage::Fragment), source);
+        assert_eq!(legacy, format!("#version 120\n#line 1 0\n{}", source));
+
+        let es = GlslDialect::Es(310).adapt(Some(ShaderStage::Geometry), "void main() {}\n");
+        assert!(es.starts_with("#version 310 es\n#extension GL_EXT_geometry_shader : require\nprecision highp float;\n"));
+        assert!(es.ends_with("#line 1 0\nvoid main() {}\n"));
+
+        assert_eq!(GlslDialect::Core(140).header(None), "#version 140\n");
+        assert_eq!(GlslDialect::Core(150).header(None), "#version 150 core\n");
+
+        let sources = MemorySource::new().with_source("main.vert", "attribute vec2 position;\n");
+        let vertex = Preprocessor::new(&sources)
+            .with_dialect(Some(GlslDialect::Core(330)))
+            .with_stage(Some(ShaderStage::Vertex))
+            .process("main.vert")
+            .unwrap();
+        assert!(vertex.source().contains("\nin vec2 position;\n"));
+    }
+
+    #[test]
+    fn versionless_shaders() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, ShaderSource::Versionless(r#"
+            layout(location = 0) in vec2 position;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+            }
+        "#)).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, ShaderSource::Versionless(r#"
+            layout(location = 0) out vec4 color;
+
+            void main() {
+                color = vec4(1.0);
+            }
+        "#)).unwrap();
+
+        RasterProgram::new(&context, &vertex_shader, &fragment_shader).unwrap();
+    }
+
+    #[test]
+    fn frag_color_without_layouts() {
+        use gpu::{Framebuffer, Image2D, ImageFormat, ColorFormat, Type, Buffer, VertexArrayObject, RasterGeometry, RasterState, Raster, ProgramBuilder};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let dialect = GlslDialect::Core(150);
+        let vertex_source = dialect.adapt(Some(ShaderStage::Vertex), r#"
+            attribute vec2 position;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+                gl_PointSize = 8.0;
+            }
+        "#);
+        let fragment_source = dialect.adapt(Some(ShaderStage::Fragment), r#"
+            void main() {
+                gl_FragColor = vec4(1.0, 2.0, 3.0, 4.0);
+            }
+        "#);
+        let vertex_shader = VertexShader::new(&context, vertex_source.as_str()).unwrap();
+        let fragment_shader = FragmentShader::new(&context, fragment_source.as_str()).unwrap();
+        let raster_program = ProgramBuilder::new()
+            .with_shader(&vertex_shader)
+            .with_shader(&fragment_shader)
+            .with_attribute_location("position", 0)
+            .build_raster(&context)
+            .unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (8, 8), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let buffer = Buffer::from_data(&context, &vec![0.0f32, 0.0]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&buffer, 0, 2).unwrap();
+
+        raster_program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+
+        let expected_data : Vec<f32> = [1.0, 2.0, 3.0, 4.0].iter().cycle().take(8 * 8 * 4).cloned().collect();
+        assert_eq!(expected_data, data_out);
+    }
+}
@@ -0,0 +1,95 @@
+mod utils;
+
+#[cfg(test)]
+mod preprocessor {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::FragmentShader;
+    use gpu::{Preprocessor, MemorySource};
+
+
+    #[test]
+    fn includes_and_defines() {
+        let sources = MemorySource::new()
+            .with_source("shaders/main.frag", "#version 330 core\n#include \"lib/color.glsl\"\nout vec4 color;\nvoid main() { color = tint(); }\n")
+            .with_source("shaders/lib/color.glsl", "#pragma once\n#include \"../common.glsl\"\nvec4 tint() { return vec4(SCALE); }\n")
+            .with_source("shaders/common.glsl", "#pragma once\nconst float ONE = 1.0;\n");
+
+        let source = Preprocessor::new(&sources)
+            .with_define("SCALE", "2.0")
+            .process("shaders/main.frag")
+            .unwrap();
+
+        assert_eq!(source.files(), &["shaders/main.frag", "shaders/lib/color.glsl", "shaders/common.glsl"]);
+        assert_eq!(source.source(), "#version 330 core\n\
+                                     #define SCALE 2.0\n\
+                                     #line 2 0\n\
+                                     #line 1 1\n\
+                                     \n\
+                                     #line 1 2\n\
+                                     \n\
+                                     const float ONE = 1.0;\n\
+                                     #line 3 1\n\
+                                     vec4 tint() { return vec4(SCALE); }\n\
+                                     #line 3 0\n\
+                                     out vec4 color;\n\
+                                     void main() { color = tint(); }\n");
+
+        assert_eq!(source.map_log("0:12(5): error: x"), "shaders/main.frag:12(5): error: x");
+        assert_eq!(source.map_log("1(3) : error C0000: x"), "shaders/lib/color.glsl(3) : error C0000: x");
+        assert_eq!(source.map_log("ERROR: 2:1: x"), "ERROR: shaders/common.glsl:1: x");
+
+        let commented = MemorySource::new().with_source("main.frag", "// Tint shader.\n\n/* Multi\n   line */\n#version 330 core\nout vec4 color;\n");
+        let source = Preprocessor::new(&commented)
+            .with_define("SCALE", "2.0")
+            .process("main.frag")
+            .unwrap();
+        assert_eq!(source.source(), "#version 330 core\n\
+                                     #define SCALE 2.0\n\
+                                     #line 6 0\n\
+                                     out vec4 color;\n");
+
+        let cyclic = MemorySource::new().with_source("a.glsl", "#include \"a.glsl\"\n");
+        assert!(Preprocessor::new(&cyclic).process("a.glsl").is_err());
+    }
+
+    #[test]
+    fn pragma_once() {
+        let sources = MemorySource::new()
+            .with_source("main.glsl", "#include \"a.glsl\"\n#include \"b.glsl\"\n#include \"a.glsl\"\n")
+            .with_source("a.glsl", "#pragma once\nfloat a;\n")
+            .with_source("b.glsl", "#include \"a.glsl\"\nfloat b;\n");
+
+        let source = Preprocessor::new(&sources).process("main.glsl").unwrap();
+
+        assert_eq!(source.files(), &["main.glsl", "a.glsl", "b.glsl"]);
+        assert_eq!(source.source(), "#line 1 0\n\
+                                     #line 1 1\n\
+                                     \n\
+                                     float a;\n\
+                                     #line 2 0\n\
+                                     #line 1 2\n\
+                                     #line 2 2\n\
+                                     float b;\n\
+                                     #line 3 0\n\
+                                     #line 4 0\n");
+        assert_eq!(source.source().matches("float a;").count(), 1);
+    }
+
+    #[test]
+    fn compile_error_location() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let sources = MemorySource::new()
+            .with_source("main.frag", "#version 330 core\n#include \"broken.glsl\"\nout vec4 color;\nvoid main() { color = vec4(1.0); }\n")
+            .with_source("broken.glsl", "float broken() {\n    return undefined_variable;\n}\n");
+        let source = Preprocessor::new(&sources).process("main.frag").unwrap();
+
+        let error = FragmentShader::new(&context, &source).err().unwrap();
+        assert!(error.contains("broken.glsl"));
+    }
+}
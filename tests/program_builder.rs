@@ -0,0 +1,125 @@
+mod utils;
+
+#[cfg(test)]
+mod program_builder {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{VertexShader, GeometryShader, FragmentShader, TessControlShader};
+    use gpu::ProgramBuilder;
+    use gpu::{Framebuffer, Image2D, ImageFormat, ColorFormat, Type};
+    use gpu::{Buffer, VertexArrayObject, RasterGeometry, RasterState, Raster};
+
+
+    #[test]
+    fn geometry_shader() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 330 core
+            in vec2 position;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+            }
+        "#).unwrap();
+
+        // Expands each point into a triangle covering the whole viewport.
+        let geometry_shader = GeometryShader::new(&context, r#"#version 330 core
+            layout(points) in;
+            layout(triangle_strip, max_vertices = 3) out;
+
+            void main() {
+                gl_Position = vec4(-1.0, -1.0, 0.0, 1.0); EmitVertex();
+                gl_Position = vec4( 3.0, -1.0, 0.0, 1.0); EmitVertex();
+                gl_Position = vec4(-1.0,  3.0, 0.0, 1.0); EmitVertex();
+                EndPrimitive();
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 330 core
+            out vec4 color;
+
+            void main() {
+                color = vec4(1.0, 2.0, 3.0, 4.0);
+            }
+        "#).unwrap();
+
+        let program = ProgramBuilder::new()
+            .with_shader(&vertex_shader)
+            .with_shader(&geometry_shader)
+            .with_shader(&fragment_shader)
+            .with_attribute_location("position", 3)
+            .with_fragment_output_location("color", 0)
+            .build_raster(&context)
+            .unwrap();
+
+        assert_eq!(program.reflect().input("position").unwrap().location, Some(3));
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (8, 8), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let buffer = Buffer::from_data(&context, &vec![0.0f32, 0.0]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&buffer, 3, 2).unwrap();
+
+        program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+
+        let expected_data : Vec<f32> = [1.0, 2.0, 3.0, 4.0].iter().cycle().take(8 * 8 * 4).cloned().collect();
+        assert_eq!(expected_data, data_out);
+    }
+
+    #[test]
+    fn invalid_stages() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 410 core
+            void main() {
+                gl_Position = vec4(0.0);
+            }
+        "#).unwrap();
+
+        let tess_control_shader = TessControlShader::new(&context, r#"#version 410 core
+            layout(vertices = 3) out;
+
+            void main() {
+                gl_out[gl_InvocationID].gl_Position = gl_in[gl_InvocationID].gl_Position;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 410 core
+            in vec4 missing;
+            out vec4 color;
+
+            void main() {
+                color = missing;
+            }
+        "#).unwrap();
+
+        assert!(ProgramBuilder::new().build(&context).is_err());
+        assert!(ProgramBuilder::new()
+            .with_shader(&vertex_shader)
+            .with_shader(&tess_control_shader)
+            .build(&context)
+            .is_err());
+        assert!(ProgramBuilder::new()
+            .with_shader(&fragment_shader)
+            .build(&context)
+            .is_err());
+
+        let error = ProgramBuilder::new()
+            .with_shader(&vertex_shader)
+            .with_shader(&fragment_shader)
+            .build(&context)
+            .err()
+            .unwrap();
+        assert!(error.starts_with("Failed to link program"));
+    }
+}
@@ -0,0 +1,56 @@
+mod utils;
+
+#[cfg(test)]
+mod program_cache {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{ComputeProgram, ProgramCache, ShaderStage, Preprocessor, MemorySource};
+
+
+    #[test]
+    fn store_and_load() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let sources = MemorySource::new().with_source("main.comp", r#"#version 430
+            layout(local_size_x = LOCAL_SIZE) in;
+
+            void main() {}
+        "#);
+        let source = Preprocessor::new(&sources).with_define("LOCAL_SIZE", "4").process("main.comp").unwrap();
+        let other = Preprocessor::new(&sources).with_define("LOCAL_SIZE", "8").process("main.comp").unwrap();
+
+        let directory = std::env::temp_dir().join("gpu-program-cache-test");
+        let cache = ProgramCache::new(&directory).unwrap();
+        cache.clear().unwrap();
+
+        let key = cache.key(&context.info(), &[(ShaderStage::Compute, source.source())]);
+        assert!(cache.load(&context, key).is_none());
+
+        let program = ComputeProgram::new_cached(&context, &cache, &source).unwrap();
+        assert_eq!(program.local_size(), (4, 1, 1));
+
+        let path = directory.join(format!("{:016x}.bin", key));
+        assert!(path.exists());
+        assert!(cache.load(&context, key).is_some());
+        let program = ComputeProgram::new_cached(&context, &cache, &source).unwrap();
+        assert_eq!(program.local_size(), (4, 1, 1));
+
+        // A corrupted binary is rejected and replaced.
+        std::fs::write(&path, [0u8; 16]).unwrap();
+        assert!(cache.load(&context, key).is_none());
+        assert!(!path.exists());
+        ComputeProgram::new_cached(&context, &cache, &source).unwrap();
+        assert!(cache.load(&context, key).is_some());
+
+
+        // Different defines make a different entry.
+        let program = ComputeProgram::new_cached(&context, &cache, &other).unwrap();
+        assert_eq!(program.local_size(), (8, 1, 1));
+
+        cache.clear().unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+mod utils;
+
+#[cfg(test)]
+mod program_pipeline {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{VertexShader, FragmentShader, ShaderStage};
+    use gpu::{ProgramBuilder, ProgramPipeline};
+    use gpu::{Framebuffer, Image2D, ImageFormat, ColorFormat, Type};
+    use gpu::{Buffer, VertexArrayObject, RasterGeometry, RasterState, Raster};
+
+
+    #[test]
+    fn mix_stages() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 410 core
+            layout(location = 0) in vec2 position;
+            out gl_PerVertex {
+                vec4 gl_Position;
+                float gl_PointSize;
+            };
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+                gl_PointSize = 8.0;
+            }
+        "#).unwrap();
+
+        let red = FragmentShader::new(&context, r#"#version 410 core
+            layout(location = 0) out vec4 color;
+
+            void main() {
+                color = vec4(1.0, 0.0, 0.0, 1.0);
+            }
+        "#).unwrap();
+
+        let green = FragmentShader::new(&context, r#"#version 410 core
+            layout(location = 0) out vec4 color;
+
+            void main() {
+                color = vec4(0.0, 1.0, 0.0, 1.0);
+            }
+        "#).unwrap();
+
+        let vertex_program = ProgramBuilder::new().with_shader(&vertex_shader).with_separable(true).build(&context).unwrap();
+        let red_program = ProgramBuilder::new().with_shader(&red).with_separable(true).build(&context).unwrap();
+        let green_program = ProgramBuilder::new().with_shader(&green).with_separable(true).build(&context).unwrap();
+        let linked_program = ProgramBuilder::new().with_shader(&vertex_shader).with_shader(&red).build(&context).unwrap();
+
+        let mut pipeline = ProgramPipeline::new(&context);
+        assert!(pipeline.use_program_stages(&linked_program, &[ShaderStage::Vertex]).is_err());
+        pipeline.use_program_stages(&vertex_program, &[ShaderStage::Vertex]).unwrap();
+        pipeline.use_program_stages(&red_program, &[ShaderStage::Fragment]).unwrap();
+        pipeline.validate().unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (8, 8), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let buffer = Buffer::from_data(&context, &vec![0.0f32, 0.0]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&buffer, 0, 2).unwrap();
+
+        pipeline.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        let expected_data : Vec<f32> = [1.0, 0.0, 0.0, 1.0].iter().cycle().take(8 * 8 * 4).cloned().collect();
+        assert_eq!(expected_data, data_out);
+
+        pipeline.use_program_stages(&green_program, &[ShaderStage::Fragment]).unwrap();
+        pipeline.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        let expected_data : Vec<f32> = [0.0, 1.0, 0.0, 1.0].iter().cycle().take(8 * 8 * 4).cloned().collect();
+        assert_eq!(expected_data, data_out);
+    }
+}
@@ -0,0 +1,80 @@
+mod utils;
+
+#[cfg(test)]
+mod program_reflection {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::VertexShader;
+    use gpu::FragmentShader;
+    use gpu::RasterProgram;
+    use gpu::UniformType;
+    use gpu::Buffer;
+    use gpu::VertexArrayObject;
+    use gpu::{VertexAttribute, AttributeType, AttributeKind};
+
+
+    #[test]
+    fn reflect() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 430 core
+            layout(location = 0) in vec2 position;
+            layout(location = 1) in uint id;
+            flat out uint vertex_id;
+            uniform mat4 transform;
+
+            void main() {
+                gl_Position = transform * vec4(position, 0.0, 1.0);
+                vertex_id = id;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 430 core
+            flat in uint vertex_id;
+            layout(location = 0) out vec4 color;
+            layout(binding = 3) uniform sampler2D albedo;
+            layout(std140, binding = 1) uniform Material {
+                vec4 tint;
+                float roughness;
+            };
+
+            void main() {
+                color = texture(albedo, vec2(0.0)) * tint * roughness + float(vertex_id);
+            }
+        "#).unwrap();
+
+        let program = RasterProgram::new(&context, &vertex_shader, &fragment_shader).unwrap();
+        let reflection = program.reflect();
+
+        assert_eq!(reflection.uniform("transform").unwrap().type_, UniformType::Mat4);
+        let albedo = reflection.uniform("albedo").unwrap();
+        assert!(albedo.type_.is_sampler());
+        assert_eq!(albedo.binding, Some(3));
+        assert_eq!(reflection.samplers().len(), 1);
+
+        assert_eq!(reflection.input("id").unwrap().location, Some(1));
+        assert_eq!(reflection.output("color").unwrap().type_, UniformType::Vec4);
+
+        let material = &reflection.uniform_blocks[0];
+        assert_eq!(material.binding, 1);
+        assert_eq!(material.members.len(), 2);
+        assert_eq!(material.members[0].offset, 0);
+        assert_eq!(material.members[1].offset, 16);
+
+        let buffer = Buffer::from_data(&context, &[0.0f32; 3]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_attribute(&buffer, 0, &VertexAttribute::new(2, AttributeType::F32)).unwrap();
+        assert!(reflection.validate_vertex_array(&vao).is_err());
+
+        vao.set_vertex_attribute(&buffer, 1, &VertexAttribute::new(1, AttributeType::U32)).unwrap();
+        assert!(reflection.validate_vertex_array(&vao).is_err());
+
+        let id = VertexAttribute::new(1, AttributeType::U32).with_kind(AttributeKind::Integer);
+        vao.set_vertex_attribute(&buffer, 1, &id).unwrap();
+        reflection.validate_vertex_array(&vao).unwrap();
+    }
+}
@@ -6,13 +6,15 @@ mod clear_program {
     use std::{thread, time};
     use super::utils::test;
 
-    use gpu::{ContextBuilder, Window};
+    use gpu::{Context, ContextBuilder, Window};
     use gpu::ContextDisplay;
     use gpu::Framebuffer;
     use gpu::VertexShader;
     use gpu::FragmentShader;
     use gpu::RasterProgram;
     use gpu::RasterGeometry;
+    use gpu::RasterState;
+    use gpu::Raster;
     use gpu::Buffer;
     use gpu::VertexArrayObject;
 
@@ -63,9 +65,9 @@ mod clear_program {
         let buffer_data = vec![0.0, 0.0, 0.0];
         let buffer = Buffer::from_data(&context, &buffer_data);
         let mut vao = VertexArrayObject::new(&context);
-        vao.set_vertex_buffer(&buffer, 0, 3);
+        vao.set_vertex_buffer(&buffer, 0, 3).unwrap();
 
-        raster_program.raster(&framebuffer, &vao, RasterGeometry::Points, 1);
+        raster_program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
 
         let capacity = dimension.0 * dimension.1 * components;
         let mut data_out : Vec<u8> = Vec::with_capacity(capacity);
@@ -140,11 +142,596 @@ mod clear_program {
         let buffer_data = vec![0.0, 0.0, 0.0];
         let buffer = Buffer::from_data(&context, &buffer_data);
         let mut vao = VertexArrayObject::new(&context);
-        vao.set_vertex_buffer(&buffer, 0, 3);
+        vao.set_vertex_buffer(&buffer, 0, 3).unwrap();
 
-        raster_program.raster(&framebuffer, &vao, RasterGeometry::Points, 1);
+        raster_program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
         let data_out : Vec<f32> = framebuffer.color().unwrap().data();
 
        assert_eq!(expected_data, data_out);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn draw_interleaved() {
+        use gpu::{Image2D, ColorFormat, ImageFormat, Type, VertexAttribute, AttributeType, AttributeKind};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in vec2 position;
+            layout(location = 1) in vec4 vertex_color;
+            out vec4 fragment_color;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+                gl_PointSize = 8.0;
+                fragment_color = vertex_color;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            in vec4 fragment_color;
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = fragment_color;
+            }
+        "#).unwrap();
+
+        let raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader)
+            .unwrap();
+
+        let components = 4;
+        let format = ImageFormat::new(ColorFormat::components(components), Type::F32);
+        let dimension = (8, 8);
+        let color = Image2D::allocate(&context, dimension, &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let mut expected_data : Vec<f32> = Vec::new();
+        for _x in 0..dimension.0 {
+            for _y in 0..dimension.1 {
+                expected_data.extend_from_slice(&[1.0, 0.0, 1.0, 1.0]);
+            }
+        }
+
+        // Two f32 for the position followed by four normalized u8 for the color.
+        #[repr(C)]
+        struct Vertex { position: [f32; 2], color: [u8; 4] }
+        let stride = std::mem::size_of::<Vertex>();
+        let buffer_data = vec![Vertex { position: [0.0, 0.0], color: [255, 0, 255, 255] }];
+        let buffer = Buffer::from_data(&context, &buffer_data);
+        let position = VertexAttribute::new(2, AttributeType::F32).with_stride(stride);
+        let color = VertexAttribute::new(4, AttributeType::U8)
+            .with_kind(AttributeKind::Normalized)
+            .with_stride(stride)
+            .with_offset(8);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_attribute(&buffer, 0, &position).unwrap();
+        vao.set_vertex_attribute(&buffer, 1, &color).unwrap();
+
+        raster_program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+
+        assert_eq!(expected_data, data_out);
+    }
+
+    #[test]
+    fn draw_indexed_range() {
+        use gpu::{Image2D, ColorFormat, ImageFormat, Type, IndexType, IndexRange};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in vec4 vertex_color;
+            out vec4 fragment_color;
+
+            void main() {
+                gl_Position = vec4(0.0, 0.0, 0.0, 1.0);
+                gl_PointSize = 8.0;
+                fragment_color = vertex_color;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            in vec4 fragment_color;
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = fragment_color;
+            }
+        "#).unwrap();
+
+        let raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader)
+            .unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let dimension = (8, 8);
+        let color = Image2D::allocate(&context, dimension, &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let colors = vec![1.0f32, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0];
+        let indices = vec![0u16, 1];
+        let buffer = Buffer::from_data(&context, &colors);
+        let index_buffer = Buffer::from_data(&context, &indices);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&buffer, 0, 4).unwrap();
+        assert!(raster_program.indexed_raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points).is_err());
+        vao.set_index_buffer(&index_buffer, IndexType::U16);
+        assert_eq!(vao.n_indices(), 2);
+
+        raster_program.indexed_raster_range(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, IndexRange::new(1, 1)).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+
+        let expected_data : Vec<f32> = [0.0, 1.0, 0.0, 1.0].iter().cycle().take(8 * 8 * 4).cloned().collect();
+        assert_eq!(expected_data, data_out);
+    }
+
+    #[test]
+    fn draw_instanced() {
+        use gpu::{Image2D, ColorFormat, ImageFormat, Type, VertexAttribute, AttributeType, IndexType, InstanceRange, ClearProgram};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in vec4 instance_color;
+            out vec4 fragment_color;
+
+            void main() {
+                gl_Position = vec4(-0.5 + float(gl_InstanceID), 0.0, 0.0, 1.0);
+                gl_PointSize = 1.0;
+                fragment_color = instance_color;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            in vec4 fragment_color;
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = fragment_color;
+            }
+        "#).unwrap();
+
+        let raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader)
+            .unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (2, 1), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+        let clear_program = ClearProgram::new(&context);
+
+        // One color per instance.
+        let colors = Buffer::from_data(&context, &vec![1.0f32, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+        let indices = Buffer::from_data(&context, &vec![0u16]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_attribute(&colors, 0, &VertexAttribute::new(4, AttributeType::F32).with_divisor(1)).unwrap();
+        vao.set_index_buffer(&indices, IndexType::U16);
+
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        raster_program.raster_instanced(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1, InstanceRange::new(0, 2)).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+
+        // gl_InstanceID starts at 0, but instanced attributes start at the first instance.
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        raster_program.indexed_raster_instanced(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, InstanceRange::new(1, 1)).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn draw_indirect() {
+        use gpu::{Image2D, ColorFormat, ImageFormat, Type, IndexType, ClearProgram};
+        use gpu::{IndirectCommands, DrawArraysIndirectCommand, DrawElementsIndirectCommand};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in float position;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 0.0, 1.0);
+                gl_PointSize = 1.0;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = vec4(1.0);
+            }
+        "#).unwrap();
+
+        let raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader)
+            .unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (4, 1), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+        let clear_program = ClearProgram::new(&context);
+
+        // One point at the center of each pixel.
+        let positions = Buffer::from_data(&context, &vec![-0.75f32, -0.25, 0.25, 0.75]);
+        let indices = Buffer::from_data(&context, &vec![0u16, 1, 2, 3]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&positions, 0, 1).unwrap();
+        vao.set_index_buffer(&indices, IndexType::U16);
+
+        let commands = vec![DrawArraysIndirectCommand::new(3, 1), DrawArraysIndirectCommand::new(0, 1), DrawArraysIndirectCommand::new(1, 1)];
+        let commands = Buffer::from_data(&context, &commands);
+
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        raster_program.raster_indirect(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, &commands, 0).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+
+        // The last two commands.
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        let offset = std::mem::size_of::<DrawArraysIndirectCommand>();
+        raster_program.multi_raster_indirect(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, IndirectCommands::new(&commands, offset).with_draw_count(2)).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+
+        // The base vertex moves the single index 1 to the vertex 3.
+        let mut command = DrawElementsIndirectCommand::new(1, 1);
+        command.base_vertex = 2;
+        let commands = Buffer::from_data(&context, &vec![command]);
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        raster_program.indexed_raster_indirect(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, &commands, 0).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn draw_primitive_restart() {
+        use gpu::{Image2D, ColorFormat, ImageFormat, Type, IndexType, IndexRange, PrimitiveRestart, ClearProgram};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in vec2 position;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = vec4(1.0);
+            }
+        "#).unwrap();
+
+        let raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader)
+            .unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (3, 1), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+        let clear_program = ClearProgram::new(&context);
+
+        // A quad covering the left pixel and another one covering the right pixel. Without
+        // restarting, the strip would connect them over the middle pixel.
+        let positions = vec![
+            -1.0f32, -1.0, -1.0, 1.0, -0.4, -1.0, -0.4, 1.0,
+             0.4, -1.0,  0.4, 1.0,  1.0, -1.0,  1.0, 1.0
+        ];
+        let indices = vec![0u16, 1, 2, 3, 0xFFFF, 4, 5, 6, 7];
+        let buffer = Buffer::from_data(&context, &positions);
+        let index_buffer = Buffer::from_data(&context, &indices);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&buffer, 0, 2).unwrap();
+        vao.set_index_buffer(&index_buffer, IndexType::U16);
+        vao.set_primitive_restart(Some(PrimitiveRestart::Fixed));
+
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        raster_program.indexed_raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::TriangleStrip).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+
+        // The second quad alone.
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        let range = IndexRange::new(5, 4);
+        raster_program.indexed_raster_range(&framebuffer, &RasterState::default(), &vao, RasterGeometry::TriangleStrip, range).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0]);
+
+        assert!(RasterGeometry::Patches(0).validate().is_err());
+        assert!(RasterGeometry::Patches(3).validate().is_ok());
+        assert!(raster_program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Patches(0), 4).is_err());
+        assert!(raster_program.indexed_raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Patches(0)).is_err());
+    }
+
+    // Draws a triangle covering the 8x8 color `Image2D` of a `Framebuffer` with the `RasterState`
+    // on top of the `clear` color, returning the first texel.
+    fn draw_triangle(context: &Context, state: &RasterState, clockwise: bool, clear: (f32, f32, f32, f32), color: (f32, f32, f32, f32)) -> Vec<f32> {
+        use gpu::{Image2D, ColorFormat, ImageFormat, Type, ClearProgram};
+
+        let vertex_shader = VertexShader::new(context, r#"#version 300 es
+            layout(location = 0) in vec2 position;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(context, r#"#version 300 es
+            precision highp float;
+            uniform vec4 fragment_color;
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = fragment_color;
+            }
+        "#).unwrap();
+
+        let raster_program = RasterProgram::new(context, &vertex_shader, &fragment_shader)
+            .unwrap();
+        raster_program.set("fragment_color", &color).unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let image = Image2D::allocate(context, (8, 8), &format);
+        let framebuffer = Framebuffer::new(context, Some(image), None, None).unwrap();
+        let mut clear_program = ClearProgram::new(context);
+        clear_program.set_color(clear);
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+
+        let mut positions = vec![-1.0f32, -1.0, 3.0, -1.0, -1.0, 3.0];
+        if clockwise {
+            positions.swap(2, 4);
+            positions.swap(3, 5);
+        }
+        let buffer = Buffer::from_data(context, &positions);
+        let mut vao = VertexArrayObject::new(context);
+        vao.set_vertex_buffer(&buffer, 0, 2).unwrap();
+
+        raster_program.raster(&framebuffer, state, &vao, RasterGeometry::Triangles, 3).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        data_out[..4].to_vec()
+    }
+
+    #[test]
+    fn blend_and_color_mask() {
+        use gpu::Blend;
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let clear = (0.25, 0.25, 0.25, 0.25);
+        let color = (0.5, 0.5, 0.5, 0.5);
+        assert_eq!(draw_triangle(&context, &RasterState::default(), false, clear, color), vec![0.5, 0.5, 0.5, 0.5]);
+
+        let state = RasterState::default().with_blend(Some(Blend::additive()));
+        assert_eq!(draw_triangle(&context, &state, false, clear, color), vec![0.75, 0.75, 0.75, 0.75]);
+
+        let state = RasterState::default().with_color_mask((true, false, true, false));
+        assert_eq!(draw_triangle(&context, &state, false, clear, color), vec![0.5, 0.25, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn cull_face() {
+        use gpu::{CullFace, FrontFace};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let clear = (0.0, 0.0, 0.0, 0.0);
+        let color = (1.0, 1.0, 1.0, 1.0);
+        let back = RasterState::default().with_cull_face(Some(CullFace::Back));
+        assert_eq!(draw_triangle(&context, &back, false, clear, color), vec![1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(draw_triangle(&context, &back, true, clear, color), vec![0.0, 0.0, 0.0, 0.0]);
+
+        let front = RasterState::default().with_cull_face(Some(CullFace::Front));
+        assert_eq!(draw_triangle(&context, &front, false, clear, color), vec![0.0, 0.0, 0.0, 0.0]);
+
+        let clockwise = back.with_front_face(FrontFace::Clockwise);
+        assert_eq!(draw_triangle(&context, &clockwise, true, clear, color), vec![1.0, 1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn depth_and_stencil() {
+        use gpu::{Depth, CompareFunction, Stencil, StencilFace};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let get = |parameter| {
+            let mut value = 0;
+            unsafe {
+                gl::GetIntegerv(parameter, &mut value);
+            }
+            value
+        };
+        let is_enabled = |capability| unsafe { gl::IsEnabled(capability) == gl::TRUE };
+
+        // The framebuffer has no depth nor stencil attachment, so the tests always pass and only
+        // the applied state is checked.
+        let clear = (0.0, 0.0, 0.0, 0.0);
+        let color = (1.0, 1.0, 1.0, 1.0);
+        let stencil = StencilFace::new(CompareFunction::Equal, 1).with_masks(0xff, 0x0f);
+        let state = RasterState::default()
+            .with_depth(Some(Depth::new(CompareFunction::Greater, false)))
+            .with_stencil(Some(Stencil::all(stencil)));
+        assert_eq!(draw_triangle(&context, &state, false, clear, color), vec![1.0, 1.0, 1.0, 1.0]);
+        assert!(is_enabled(gl::DEPTH_TEST));
+        assert_eq!(get(gl::DEPTH_FUNC) as u32, gl::GREATER);
+        assert_eq!(get(gl::DEPTH_WRITEMASK) as u8, gl::FALSE);
+        assert!(is_enabled(gl::STENCIL_TEST));
+        assert_eq!(get(gl::STENCIL_FUNC) as u32, gl::EQUAL);
+        assert_eq!(get(gl::STENCIL_WRITEMASK), 0x0f);
+        assert_eq!(get(gl::STENCIL_BACK_WRITEMASK), 0x0f);
+
+        // The default state disables both tests and writes every depth and stencil bit again.
+        assert_eq!(draw_triangle(&context, &RasterState::default(), false, clear, color), vec![1.0, 1.0, 1.0, 1.0]);
+        assert!(!is_enabled(gl::DEPTH_TEST));
+        assert_eq!(get(gl::DEPTH_WRITEMASK) as u8, gl::TRUE);
+        assert!(!is_enabled(gl::STENCIL_TEST));
+        assert_eq!(get(gl::STENCIL_WRITEMASK) & 0xff, 0xff);
+        assert_eq!(get(gl::STENCIL_BACK_WRITEMASK) & 0xff, 0xff);
+    }
+
+    #[test]
+    fn set_uniforms_by_name() {
+        use gpu::{Image2D, ColorFormat, ImageFormat, Type};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in vec3 position;
+            uniform float point_size;
+
+            void main() {
+                gl_Position = vec4(position, 1.0);
+                gl_PointSize = point_size;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            uniform vec4 colors[2];
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = colors[1];
+            }
+        "#).unwrap();
+
+        let mut raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader)
+            .unwrap();
+
+        raster_program.set("point_size", &8.0f32).unwrap();
+        raster_program.set("colors", &[(0.0f32, 0.0, 0.0, 0.0), (4.0, 3.0, 2.0, 1.0)]).unwrap();
+        raster_program.set("colors[1]", &(1.0f32, 2.0, 3.0, 4.0)).unwrap();
+        assert!(raster_program.set("colors", &[[1.0f32; 4]; 4]).is_err());
+        assert!(raster_program.set("colors", &[(0.0f32, 0.0, 0.0, 0.0); 3]).is_err());
+        assert!(raster_program.set("colors[1]", &[(0.0f32, 0.0, 0.0, 0.0); 2]).is_err());
+        assert!(raster_program.set("point_size", &[8.0f32]).is_err());
+        assert!(raster_program.set("point_size", &8).is_err());
+        assert!(raster_program.set("missing", &1.0f32).is_err());
+        raster_program.set_lenient(true);
+        raster_program.set("missing", &1.0f32).unwrap();
+
+        let format = ImageFormat::new(ColorFormat::components(4), Type::F32);
+        let color = Image2D::allocate(&context, (8, 8), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let buffer = Buffer::from_data(&context, &vec![0.0, 0.0, 0.0]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&buffer, 0, 3).unwrap();
+
+        raster_program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+
+        let expected_data : Vec<f32> = [1.0, 2.0, 3.0, 4.0].iter().cycle().take(8 * 8 * 4).cloned().collect();
+        assert_eq!(expected_data, data_out);
+    }
+
+    #[test]
+    fn viewport_array() {
+        use gpu::{GeometryShader, ProgramBuilder, Image2D, ImageFormat, ColorFormat, Type, Viewport, Scissor, ClearProgram};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 410 core
+            in vec2 position;
+
+            void main() {
+                gl_Position = vec4(position, 0.0, 1.0);
+            }
+        "#).unwrap();
+
+        // Expands each point into a triangle covering the whole viewport for the viewports in
+        // [first, first + count).
+        let geometry_shader = GeometryShader::new(&context, r#"#version 410 core
+            layout(points) in;
+            layout(triangle_strip, max_vertices = 12) out;
+
+            uniform int first;
+            uniform int count;
+
+            void main() {
+                for (int i = first; i < first + count; i++) {
+                    gl_ViewportIndex = i; gl_Position = vec4(-1.0, -1.0, 0.0, 1.0); EmitVertex();
+                    gl_ViewportIndex = i; gl_Position = vec4( 3.0, -1.0, 0.0, 1.0); EmitVertex();
+                    gl_ViewportIndex = i; gl_Position = vec4(-1.0,  3.0, 0.0, 1.0); EmitVertex();
+                    EndPrimitive();
+                }
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 410 core
+            out vec4 color;
+
+            void main() {
+                color = vec4(1.0);
+            }
+        "#).unwrap();
+
+        let program = ProgramBuilder::new()
+            .with_shader(&vertex_shader)
+            .with_shader(&geometry_shader)
+            .with_shader(&fragment_shader)
+            .with_attribute_location("position", 0)
+            .with_fragment_output_location("color", 0)
+            .build_raster(&context)
+            .unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (4, 1), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+        let clear_program = ClearProgram::new(&context);
+
+        let buffer = Buffer::from_data(&context, &vec![0.0f32, 0.0]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&buffer, 0, 2).unwrap();
+
+        // One viewport and one scissor per pixel.
+        let viewports = (0..4).map(|x| Viewport::new((x, 0), (1, 1))).collect();
+        let scissors = (0..4).map(|x| Scissor::new((x, 0), (1, 1))).collect();
+        let state = RasterState::new().with_viewports(viewports).with_scissors(scissors);
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        program.set("first", &0).unwrap();
+        program.set("count", &4).unwrap();
+        program.raster(&framebuffer, &state, &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![1.0; 4 * 4]);
+
+        // The slots after the given ones cover the whole `Framebuffer` again.
+        let viewports = (0..2).map(|x| Viewport::new((x, 0), (1, 1))).collect();
+        let scissors = (0..2).map(|x| Scissor::new((x, 0), (1, 1))).collect();
+        let state = RasterState::new().with_viewports(viewports).with_scissors(scissors);
+        clear_program.clear(&framebuffer, ClearProgram::COLOR);
+        program.set("first", &3).unwrap();
+        program.set("count", &1).unwrap();
+        program.raster(&framebuffer, &state, &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+        assert_eq!(data_out, vec![1.0; 4 * 4]);
+    }
+}
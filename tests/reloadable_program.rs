@@ -0,0 +1,108 @@
+mod utils;
+
+#[cfg(test)]
+mod reloadable_program {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{ComputeProgram, ReloadableProgram, ShaderStage};
+
+
+    #[test]
+    fn reload() {
+        use gpu::{Image2D, ImageFormat, ColorFormat, Type, StorageImageBinding, ImageAccess, MemoryBarrier};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let directory = std::env::temp_dir().join("gpu-reloadable-program-test");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("size.glsl"), "#define SIZE 4\n").unwrap();
+        std::fs::write(directory.join("main.comp"), "#version 430\n#include \"size.glsl\"\nlayout(local_size_x = SIZE) in;\nuniform float scale;\nlayout(r32f, binding = 0) writeonly uniform image2D image;\nvoid main() { imageStore(image, ivec2(gl_GlobalInvocationID.xy), vec4(scale * float(SIZE))); }\n").unwrap();
+
+        let format = ImageFormat::new(ColorFormat::R, Type::F32);
+        let image = Image2D::allocate(&context, (8, 1), &format);
+        let binding = StorageImageBinding::new().with_access(ImageAccess::WriteOnly);
+        let compute = |program: &ComputeProgram| -> Vec<f32> {
+            program.bind_storage_image(&image, 0, &binding).unwrap();
+            program.compute((1, 1, 1));
+            context.memory_barrier(MemoryBarrier::TEXTURE_UPDATE);
+            image.data()
+        };
+
+        let mut program : ReloadableProgram<ComputeProgram> = ReloadableProgram::new(&context, &directory, &[(ShaderStage::Compute, "main.comp")], &[]).unwrap();
+        assert_eq!(program.files().len(), 2);
+        assert_eq!(program.program().local_size(), (4, 1, 1));
+        program.set("scale", 2.0f32).unwrap();
+        assert!(!program.has_changed());
+        assert_eq!(compute(program.program())[..4], [8.0; 4]);
+
+        // A broken include keeps the previous program.
+        std::fs::write(directory.join("size.glsl"), "#define SIZE\n").unwrap();
+        assert!(program.reload(&context).is_err());
+        assert!(program.last_error().is_some());
+        assert_eq!(program.program().local_size(), (4, 1, 1));
+
+        // The uniforms are set again to the rebuilt program.
+        std::fs::write(directory.join("size.glsl"), "#define SIZE 8\n").unwrap();
+        program.reload(&context).unwrap();
+        assert!(program.last_error().is_none());
+        assert_eq!(program.program().local_size(), (8, 1, 1));
+        assert_eq!(compute(program.program()), vec![16.0; 8]);
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn reload_if_changed() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let directory = std::env::temp_dir().join("gpu-reloadable-program-changed-test");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("size.glsl"), "#define SIZE 4\n").unwrap();
+        std::fs::write(directory.join("main.comp"), "#version 430\n#include \"size.glsl\"\nlayout(local_size_x = SIZE) in;\nvoid main() {}\n").unwrap();
+
+        let mut program : ReloadableProgram<ComputeProgram> = ReloadableProgram::new(&context, &directory, &[(ShaderStage::Compute, "main.comp")], &[]).unwrap();
+        assert!(!program.reload_if_changed(&context).unwrap());
+
+        // Changes are noticed by the watcher asynchronously, or by polling the modification times.
+        let wait_for_change = |program: &ReloadableProgram<ComputeProgram>| {
+            for _ in 0..100 {
+                if program.has_changed() {
+                    return true
+                }
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+            false
+        };
+
+        // Replacing the file, as editors saving to a new file do, is also noticed.
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        std::fs::write(directory.join("size.tmp"), "#define SIZE 8\n").unwrap();
+        std::fs::rename(directory.join("size.tmp"), directory.join("size.glsl")).unwrap();
+        assert!(wait_for_change(&program));
+        assert!(program.reload_if_changed(&context).unwrap());
+        assert_eq!(program.program().local_size(), (8, 1, 1));
+        assert!(!program.has_changed());
+
+        // A failed rebuild isn't retried until the files change again.
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        std::fs::write(directory.join("size.glsl"), "#define SIZE\n").unwrap();
+        assert!(wait_for_change(&program));
+        assert!(program.reload_if_changed(&context).is_err());
+        assert!(!program.reload_if_changed(&context).unwrap());
+
+        std::thread::sleep(std::time::Duration::from_millis(10));
+        std::fs::write(directory.join("size.glsl"), "#define SIZE 2\n").unwrap();
+        assert!(wait_for_change(&program));
+        assert!(program.reload_if_changed(&context).unwrap());
+        assert_eq!(program.program().local_size(), (2, 1, 1));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,48 @@
+mod utils;
+
+#[cfg(test)]
+mod ring_buffer {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::RingBuffer;
+    use gpu::BufferRange;
+
+
+    #[test]
+    fn push() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let mut ring_buffer = RingBuffer::new(&context, 64);
+        assert_eq!(ring_buffer.size(), 64);
+
+        let first  = ring_buffer.push(&[1.0f32, 2.0, 3.0], 4).unwrap();
+        let second = ring_buffer.push(&[4.0f32], 16).unwrap();
+        assert_eq!(first, BufferRange::new(0, 12));
+        assert_eq!(second, BufferRange::new(16, 4));
+
+        let data_out : Vec<f32> = ring_buffer.buffer().data();
+        assert_eq!(&data_out[0..3], &[1.0, 2.0, 3.0]);
+        assert_eq!(data_out[4], 4.0);
+    }
+
+    #[test]
+    fn wrap_around() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let mut ring_buffer = RingBuffer::new(&context, 64);
+        for frame in 0..8 {
+            let range = ring_buffer.push(&[frame as f32; 10], 4).unwrap();
+            assert!(range.offset + range.size <= 64);
+            ring_buffer.finish_frame();
+        }
+
+        assert!(ring_buffer.push(&[0.0f32; 17], 4).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "naga")]
+
+mod utils;
+
+#[cfg(test)]
+mod shader_validation {
+    use super::utils::test;
+    use gpu::{Shader, ShaderSource, ShaderStage, MemorySource, Preprocessor};
+
+
+    #[test]
+    fn validate_without_context() {
+        Shader::validate(ShaderStage::Fragment, r#"#version 450
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = vec4(1.0);
+            }
+        "#).unwrap();
+
+        Shader::validate(ShaderStage::Compute, ShaderSource::Versionless(r#"
+            layout(local_size_x = 1) in;
+            void main() {}
+        "#)).unwrap();
+
+        let error = Shader::validate(ShaderStage::Vertex, "#version 450\nvoid main() {\n    gl_Position = undeclared;\n}\n").err().unwrap();
+        assert_eq!(error.stage(), ShaderStage::Vertex);
+        assert_eq!(error.errors()[0].line, Some(3));
+
+        assert!(Shader::validate(ShaderStage::Geometry, "#version 450\nvoid main() {}\n").is_err());
+    }
+
+    #[test]
+    fn validate_preprocessed() {
+        let provider = MemorySource::new()
+            .with_source("main.frag", "#version 450\n#include \"color.glsl\"\nlayout(location = 0) out vec4 color;\nvoid main() { color = base_color(); }\n")
+            .with_source("color.glsl", "vec4 base_color() {\n    return undeclared;\n}\n");
+        let source = Preprocessor::new(&provider).process("main.frag").unwrap();
+
+        let error = Shader::validate(ShaderStage::Fragment, &source).err().unwrap();
+        let diagnostic = &error.errors()[0];
+        assert_eq!(diagnostic.file.as_deref(), Some("color.glsl"));
+        assert_eq!(diagnostic.line, Some(2));
+    }
+
+    #[test]
+    fn cross_compile_spirv() {
+        use gpu::GlslDialect;
+
+        // A compute shader storing `vec4(1.0, 2.0, 3.0, 4.0)` to the image at binding 2.
+        let spirv = include_bytes!("fixtures/image_store.spv");
+
+        let source = Shader::cross_compile(ShaderStage::Compute, spirv, "main", GlslDialect::Core(450)).unwrap();
+        assert!(source.starts_with("#version 450"));
+        assert!(source.contains("binding = 2"));
+        assert!(source.contains("local_size_x = 4"));
+
+        assert!(Shader::cross_compile(ShaderStage::Compute, &spirv[..16], "main", GlslDialect::Core(450)).is_err());
+        assert!(Shader::cross_compile(ShaderStage::Compute, spirv, "missing", GlslDialect::Core(450)).is_err());
+
+        // Copies the image at set 0 to the image at set 1, which share binding 0 in `OpenGL`.
+        let two_sets = include_bytes!("fixtures/two_sets.spv");
+        assert!(Shader::cross_compile(ShaderStage::Compute, two_sets, "main", GlslDialect::Core(450)).is_err());
+    }
+
+    #[test]
+    fn from_spirv() {
+        use gpu::{ContextBuilder, ContextDisplay, ComputeShader, ComputeProgram};
+        use gpu::{Image2D, ImageFormat, ColorFormat, Type, StorageImageBinding, ImageAccess, MemoryBarrier};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let shader = ComputeShader::from_spirv(&context, include_bytes!("fixtures/image_store.spv"), "main").unwrap();
+        let program = ComputeProgram::new(&context, &shader).unwrap();
+        assert_eq!(program.local_size(), (4, 1, 1));
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let image = Image2D::allocate(&context, (4, 1), &format);
+        let binding = StorageImageBinding::new().with_access(ImageAccess::WriteOnly);
+        program.bind_storage_image(&image, 2, &binding).unwrap();
+        program.compute((1, 1, 1));
+        context.memory_barrier(MemoryBarrier::TEXTURE_UPDATE);
+
+        let data : Vec<f32> = image.data();
+        let expected_data : Vec<f32> = [1.0, 2.0, 3.0, 4.0].iter().cycle().take(4 * 4).cloned().collect();
+        assert_eq!(data, expected_data);
+    }
+}
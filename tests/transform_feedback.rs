@@ -0,0 +1,129 @@
+mod utils;
+
+#[cfg(test)]
+mod transform_feedback {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{VertexShader, FragmentShader, RasterProgram, RasterGeometry, Raster};
+    use gpu::{Buffer, VertexArrayObject, TransformFeedback, TransformFeedbackMode};
+
+
+    #[test]
+    fn capture_particles() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in vec2 position;
+            layout(location = 1) in vec2 velocity;
+            out vec2 next_position;
+            out vec2 next_velocity;
+
+            void main() {
+                next_position = position + velocity;
+                next_velocity = velocity * 0.5;
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            void main() {}
+        "#).unwrap();
+
+        let interleaved = RasterProgram::new_with_transform_feedback(&context, &vertex_shader, &fragment_shader, &["next_position", "next_velocity"], TransformFeedbackMode::Interleaved).unwrap();
+        let separate = RasterProgram::new_with_transform_feedback(&context, &vertex_shader, &fragment_shader, &["next_position", "next_velocity"], TransformFeedbackMode::Separate).unwrap();
+
+        let positions = Buffer::from_data(&context, &vec![0.0f32, 0.0, 1.0, 2.0, 3.0, 4.0]);
+        let velocities = Buffer::from_data(&context, &vec![1.0f32, 1.0, 2.0, 2.0, 4.0, 4.0]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&positions, 0, 2).unwrap();
+        vao.set_vertex_buffer(&velocities, 1, 2).unwrap();
+
+        let output = Buffer::allocate(&context, 3 * 4 * 4);
+        let transform_feedback = TransformFeedback::new(&context);
+        transform_feedback.bind_buffer(0, &output).unwrap();
+        interleaved.capture(&transform_feedback, &vao, RasterGeometry::Points, RasterGeometry::Points, 3).unwrap();
+        assert_eq!(transform_feedback.primitives_written(), 3);
+        let data : Vec<f32> = output.data();
+        assert_eq!(data, vec![1.0, 1.0, 0.5, 0.5, 3.0, 4.0, 1.0, 1.0, 7.0, 8.0, 2.0, 2.0]);
+
+        let next_positions = Buffer::allocate(&context, 3 * 2 * 4);
+        let next_velocities = Buffer::allocate(&context, 2 * 2 * 4);
+        let transform_feedback = TransformFeedback::new(&context);
+        transform_feedback.bind_buffer(0, &next_positions).unwrap();
+        transform_feedback.bind_buffer(1, &next_velocities).unwrap();
+        separate.capture(&transform_feedback, &vao, RasterGeometry::Points, RasterGeometry::Points, 3).unwrap();
+        assert_eq!(transform_feedback.primitives_written(), 2);
+        let data : Vec<f32> = next_positions.data();
+        assert_eq!(&data[..4], &[1.0, 1.0, 3.0, 4.0]);
+        let data : Vec<f32> = next_velocities.data();
+        assert_eq!(data, vec![0.5, 0.5, 1.0, 1.0]);
+
+        assert!(separate.capture(&transform_feedback, &vao, RasterGeometry::Points, RasterGeometry::Patches(3), 3).is_err());
+        assert!(separate.capture(&transform_feedback, &vao, RasterGeometry::Points, RasterGeometry::TriangleStrip, 3).is_err());
+        assert!(separate.capture(&transform_feedback, &vao, RasterGeometry::Points, RasterGeometry::LineLoop, 3).is_err());
+        assert!(separate.capture(&transform_feedback, &vao, RasterGeometry::Patches(0), RasterGeometry::Points, 3).is_err());
+        assert!(transform_feedback.bind_buffer(0, &Buffer::allocate(&context, 0)).is_err());
+    }
+
+    #[test]
+    fn capture_geometry_shader() {
+        use gpu::{GeometryShader, ProgramBuilder};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 330 core
+            in float value;
+            out float vertex_value;
+
+            void main() {
+                vertex_value = value;
+            }
+        "#).unwrap();
+
+        // Expands each point into a line from its value to its value doubled.
+        let geometry_shader = GeometryShader::new(&context, r#"#version 330 core
+            layout(points) in;
+            layout(line_strip, max_vertices = 2) out;
+            in float vertex_value[];
+            out float captured;
+
+            void main() {
+                captured = vertex_value[0];       EmitVertex();
+                captured = vertex_value[0] * 2.0; EmitVertex();
+                EndPrimitive();
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 330 core
+            void main() {}
+        "#).unwrap();
+
+        let program = ProgramBuilder::new()
+            .with_shader(&vertex_shader)
+            .with_shader(&geometry_shader)
+            .with_shader(&fragment_shader)
+            .with_attribute_location("value", 0)
+            .with_transform_feedback_varyings(&["captured"], TransformFeedbackMode::Interleaved)
+            .build_raster(&context)
+            .unwrap();
+
+        let values = Buffer::from_data(&context, &vec![1.0f32, 2.0]);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer(&values, 0, 1).unwrap();
+
+        let output = Buffer::allocate(&context, 4 * 4);
+        let transform_feedback = TransformFeedback::new(&context);
+        transform_feedback.bind_buffer(0, &output).unwrap();
+        program.capture(&transform_feedback, &vao, RasterGeometry::Points, RasterGeometry::Lines, 2).unwrap();
+        assert_eq!(transform_feedback.primitives_written(), 2);
+        let data : Vec<f32> = output.data();
+        assert_eq!(data, vec![1.0, 2.0, 2.0, 4.0]);
+    }
+}
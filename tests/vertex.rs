@@ -0,0 +1,92 @@
+#![cfg(feature = "derive")]
+
+mod utils;
+
+#[cfg(test)]
+mod vertex {
+    use super::utils::test;
+    use gpu::{Vertex, VertexAttribute, AttributeType, AttributeKind};
+
+
+    #[derive(Vertex)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct MyVertex {
+        position : [f32; 3],
+        #[vertex(location = 3, normalized)]
+        color    : [u8; 4],
+        #[vertex(integer)]
+        id       : u32
+    }
+
+    #[test]
+    fn attributes() {
+        let stride = std::mem::size_of::<MyVertex>();
+        assert_eq!(stride, 20);
+
+        let attributes = <MyVertex as gpu::Vertex>::attributes();
+        assert_eq!(attributes, vec![
+            (0, VertexAttribute::new(3, AttributeType::F32).with_stride(stride)),
+            (3, VertexAttribute::new(4, AttributeType::U8)
+                .with_kind(AttributeKind::Normalized)
+                .with_stride(stride)
+                .with_offset(12)),
+            (2, VertexAttribute::new(1, AttributeType::U32)
+                .with_kind(AttributeKind::Integer)
+                .with_stride(stride)
+                .with_offset(16))
+        ]);
+    }
+
+    #[test]
+    fn draw_vertex_buffer_of() {
+        use gpu::{ContextBuilder, ContextDisplay, VertexShader, FragmentShader, RasterProgram};
+        use gpu::{Framebuffer, Image2D, ColorFormat, ImageFormat, Type};
+        use gpu::{Buffer, VertexArrayObject, RasterGeometry, RasterState, Raster};
+
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let vertex_shader = VertexShader::new(&context, r#"#version 300 es
+            layout(location = 0) in vec3 position;
+            layout(location = 3) in vec4 vertex_color;
+            layout(location = 2) in uint id;
+            out vec4 fragment_color;
+
+            void main() {
+                gl_Position = vec4(position, 1.0);
+                gl_PointSize = 8.0;
+                fragment_color = vertex_color * float(id);
+            }
+        "#).unwrap();
+
+        let fragment_shader = FragmentShader::new(&context, r#"#version 300 es
+            precision highp float;
+            in vec4 fragment_color;
+            layout(location = 0) out vec4 color;
+            void main() {
+                color = fragment_color;
+            }
+        "#).unwrap();
+
+        let raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader)
+            .unwrap();
+
+        let format = ImageFormat::new(ColorFormat::RGBA, Type::F32);
+        let color = Image2D::allocate(&context, (8, 8), &format);
+        let framebuffer = Framebuffer::new(&context, Some(color), None, None).unwrap();
+
+        let buffer_data = vec![MyVertex { position: [0.0, 0.0, 0.0], color: [255, 0, 255, 255], id: 2 }];
+        let buffer = Buffer::from_data(&context, &buffer_data);
+        let mut vao = VertexArrayObject::new(&context);
+        vao.set_vertex_buffer_of::<MyVertex>(&buffer).unwrap();
+
+        raster_program.raster(&framebuffer, &RasterState::default(), &vao, RasterGeometry::Points, 1).unwrap();
+        let data_out : Vec<f32> = framebuffer.color().unwrap().data();
+
+        let expected_data : Vec<f32> = [2.0, 0.0, 2.0, 2.0].iter().cycle().take(8 * 8 * 4).cloned().collect();
+        assert_eq!(expected_data, data_out);
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "wgsl")]
+
+mod utils;
+
+#[cfg(test)]
+mod wgsl {
+    use super::utils::test;
+    use gpu::ContextBuilder;
+    use gpu::ContextDisplay;
+    use gpu::{VertexShader, FragmentShader, RasterProgram, BindingKind};
+
+
+    const SOURCE : &str = r#"
+        struct Camera { view: mat4x4<f32> }
+        struct Output { @builtin(position) position: vec4<f32>, @location(0) uv: vec2<f32> }
+
+        @group(0) @binding(0) var<uniform> camera: Camera;
+        @group(1) @binding(0) var albedo: texture_2d<f32>;
+        @group(1) @binding(1) var albedo_sampler: sampler;
+
+        @vertex
+        fn vertex_main(@location(0) position: vec2<f32>) -> Output {
+            return Output(camera.view * vec4<f32>(position, 0.0, 1.0), position);
+        }
+
+        @fragment
+        fn fragment_main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+            return textureSample(albedo, albedo_sampler, uv);
+        }
+    "#;
+
+    #[test]
+    fn translate_and_bind() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        let (vertex_shader, vertex_bindings) = VertexShader::from_wgsl(&context, SOURCE, "vertex_main").unwrap();
+        let (fragment_shader, fragment_bindings) = FragmentShader::from_wgsl(&context, SOURCE, "fragment_main").unwrap();
+        assert_eq!(vertex_bindings.bindings().len(), 3);
+
+        let camera = vertex_bindings.get("camera").unwrap();
+        assert_eq!((camera.group, camera.binding, camera.kind, camera.unit), (0, 0, BindingKind::UniformBuffer, Some(0)));
+        assert_eq!(fragment_bindings.unit("albedo"), Some(0));
+        assert_eq!(fragment_bindings.unit("albedo_sampler"), Some(0));
+        assert_eq!(vertex_bindings.unit("albedo"), fragment_bindings.unit("albedo"));
+
+        let raster_program = RasterProgram::new(&context, &vertex_shader, &fragment_shader).unwrap();
+        raster_program.apply_bindings(&vertex_bindings).unwrap();
+        raster_program.apply_bindings(&fragment_bindings).unwrap();
+
+        assert!(VertexShader::from_wgsl(&context, SOURCE, "missing").is_err());
+    }
+
+    #[test]
+    fn combined_samplers() {
+        let context_builder = ContextBuilder::new().with_display(ContextDisplay::None);
+        let context = context_builder.build();
+
+        context.make_current().unwrap();
+
+        // `nearest` is sampled with two textures, so it must be bound to both of their units.
+        let source = r#"
+            @group(0) @binding(0) var first: texture_2d<f32>;
+            @group(0) @binding(1) var second: texture_2d<f32>;
+            @group(0) @binding(2) var nearest: sampler;
+            @group(0) @binding(3) var linear: sampler;
+
+            @fragment
+            fn main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+                return textureSample(second, nearest, uv) + textureSample(first, nearest, uv);
+            }
+        "#;
+
+        let (_, bindings) = FragmentShader::from_wgsl(&context, source, "main").unwrap();
+        assert_eq!(bindings.get("first").unwrap().glsl_names.len(), 1);
+        assert_eq!(bindings.get("second").unwrap().glsl_names.len(), 1);
+        assert!(bindings.get("nearest").unwrap().glsl_names.is_empty());
+        assert_eq!(bindings.unit("first"), Some(0));
+        assert_eq!(bindings.unit("second"), Some(1));
+        assert_eq!(bindings.unit("nearest"), Some(0));
+        assert_eq!(bindings.get("nearest").unwrap().texture_units, vec![0, 1]);
+        assert_eq!(bindings.unit("linear"), None);
+        assert!(bindings.get("linear").unwrap().texture_units.is_empty());
+
+        // A texture unit has a single sampler, so `first` can't be sampled with two.
+        let source = r#"
+            @group(0) @binding(0) var first: texture_2d<f32>;
+            @group(0) @binding(2) var nearest: sampler;
+            @group(0) @binding(3) var linear: sampler;
+
+            @fragment
+            fn main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+                return textureSample(first, nearest, uv) + textureSample(first, linear, uv);
+            }
+        "#;
+        assert!(FragmentShader::from_wgsl(&context, source, "main").is_err());
+    }
+}